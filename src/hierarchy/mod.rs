@@ -0,0 +1,142 @@
+use std::collections::VecDeque;
+
+use crate::{simple::SimpleKey, simple_assoc::SimpleAssocSurotto};
+
+/// Parent and children links between keys, e.g. for scene graphs or UI trees.
+///
+/// Every key has at most one parent. Keys without a parent are roots of their own tree.
+pub struct Hierarchy<K: SimpleKey> {
+    parents: SimpleAssocSurotto<K, K>,
+    children: SimpleAssocSurotto<K, Vec<K>>,
+}
+
+impl<K: SimpleKey> Hierarchy<K> {
+    /// Constructs a new, empty `Hierarchy<K>`.
+    pub const fn new() -> Self {
+        Self {
+            parents: SimpleAssocSurotto::new(),
+            children: SimpleAssocSurotto::new(),
+        }
+    }
+
+    /// Attaches the child as the last child of the parent,
+    /// detaching it from its previous parent first.
+    ///
+    /// Returns `false` and does nothing if the parent is the child itself
+    /// or one of its descendants, as that would create a cycle.
+    pub fn attach(&mut self, child: K, parent: K) -> bool {
+        if self.is_ancestor_or_self(child, parent) {
+            return false;
+        }
+
+        self.detach(child);
+        self.parents.insert(child, parent);
+        self.children.entry(parent).or_default().push(child);
+        true
+    }
+
+    /// Detaches the child from its parent, returning the parent.
+    /// The children of the child stay attached to it.
+    pub fn detach(&mut self, child: K) -> Option<K> {
+        let parent = self.parents.remove(child)?;
+        if let Some(siblings) = self.children.get_mut(parent) {
+            siblings.retain(|sibling| sibling.idx() != child.idx());
+            if siblings.is_empty() {
+                self.children.remove(parent);
+            }
+        }
+        Some(parent)
+    }
+
+    /// Detaches the key and removes all links inside of its subtree,
+    /// returning the keys of the subtree in depth-first order, starting with the key.
+    ///
+    /// Use this to find all keys whose data has to be removed together with the key.
+    pub fn remove_subtree(&mut self, root: K) -> Vec<K> {
+        self.detach(root);
+        let keys: Vec<K> = self.depth_first(root).collect();
+        for &key in &keys {
+            self.parents.remove(key);
+            self.children.remove(key);
+        }
+        keys
+    }
+
+    /// Returns the parent of the key, if it has one.
+    pub fn parent(&self, key: K) -> Option<K> {
+        self.parents.get(key).copied()
+    }
+
+    /// Returns the children of the key in the order they were attached.
+    pub fn children(&self, key: K) -> &[K] {
+        self.children.get(key).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns `true` if `ancestor` is the key itself or one of its ancestors.
+    pub fn is_ancestor_or_self(&self, ancestor: K, key: K) -> bool {
+        let mut current = Some(key);
+        while let Some(key) = current {
+            if key.idx() == ancestor.idx() {
+                return true;
+            }
+            current = self.parent(key);
+        }
+        false
+    }
+
+    /// An iterator visiting the key and all of its descendants in depth-first pre-order.
+    /// The iterator element type is `K`.
+    pub fn depth_first(&self, root: K) -> DepthFirst<'_, K> {
+        DepthFirst {
+            hierarchy: self,
+            stack: vec![root],
+        }
+    }
+
+    /// An iterator visiting the key and all of its descendants in breadth-first order.
+    /// The iterator element type is `K`.
+    pub fn breadth_first(&self, root: K) -> BreadthFirst<'_, K> {
+        BreadthFirst {
+            hierarchy: self,
+            queue: VecDeque::from([root]),
+        }
+    }
+}
+
+impl<K: SimpleKey> Default for Hierarchy<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct DepthFirst<'a, K: SimpleKey> {
+    hierarchy: &'a Hierarchy<K>,
+    stack: Vec<K>,
+}
+
+impl<'a, K: SimpleKey> Iterator for DepthFirst<'a, K> {
+    type Item = K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let key = self.stack.pop()?;
+        self.stack
+            .extend(self.hierarchy.children(key).iter().rev().copied());
+        Some(key)
+    }
+}
+
+pub struct BreadthFirst<'a, K: SimpleKey> {
+    hierarchy: &'a Hierarchy<K>,
+    queue: VecDeque<K>,
+}
+
+impl<'a, K: SimpleKey> Iterator for BreadthFirst<'a, K> {
+    type Item = K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let key = self.queue.pop_front()?;
+        self.queue
+            .extend(self.hierarchy.children(key).iter().copied());
+        Some(key)
+    }
+}
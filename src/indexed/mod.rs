@@ -0,0 +1,89 @@
+use std::{collections::HashMap, hash::Hash};
+
+use crate::simple::{
+    iterators::{Iter, Keys, Values},
+    SimpleKey, SimpleSurotto,
+};
+
+/// A [`SimpleSurotto`] which also indexes its values, so the key of a value can be looked up.
+///
+/// Every value is stored only once, inserting an equal value again returns the existing key.
+/// The values can't be mutated, as that would let the index drift out of sync.
+pub struct IndexedSurotto<K: SimpleKey, V> {
+    inner: SimpleSurotto<K, V>,
+    index: HashMap<V, K>,
+}
+
+impl<K: SimpleKey, V: Hash + Eq + Clone> IndexedSurotto<K, V> {
+    /// Constructs a new, empty `IndexedSurotto<K, V>`.
+    pub fn new() -> Self {
+        Self {
+            inner: SimpleSurotto::new(),
+            index: HashMap::new(),
+        }
+    }
+
+    /// Inserts a value into the surotto if no equal value is present, returning its key.
+    /// Otherwise the key of the present value is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the key index is `usize::MAX`.
+    pub fn insert_unique(&mut self, value: V) -> K {
+        if let Some(&key) = self.index.get(&value) {
+            return key;
+        }
+        let key = self.inner.insert(value.clone());
+        self.index.insert(value, key);
+        key
+    }
+
+    /// Returns the key of the value, if it is present.
+    pub fn key_of(&self, value: &V) -> Option<K> {
+        self.index.get(value).copied()
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    pub fn get(&self, key: K) -> &V {
+        self.inner.get(key)
+    }
+
+    /// Returns the underlying surotto.
+    pub fn as_inner(&self) -> &SimpleSurotto<K, V> {
+        &self.inner
+    }
+
+    /// Returns the number of elements in the surotto.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns `true` if the surotto contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// An iterator visiting all key-value pairs in insertion order.
+    /// The iterator element type is `(K, &'a V)`.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        self.inner.iter()
+    }
+
+    /// An iterator visiting all keys in insertion order.
+    /// The iterator element type is `K`.
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        self.inner.keys()
+    }
+
+    /// An iterator visiting all values in insertion order.
+    /// The iterator element type is `&'a V`.
+    pub fn values(&self) -> Values<'_, K, V> {
+        self.inner.values()
+    }
+}
+
+impl<K: SimpleKey, V: Hash + Eq + Clone> Default for IndexedSurotto<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
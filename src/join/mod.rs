@@ -0,0 +1,141 @@
+use std::{iter, marker::PhantomData};
+
+use crate::simple::SimpleKey;
+
+/// A borrowed surotto which can take part in a [`join`].
+///
+/// Implemented for shared and mutable references to
+/// [`SimpleSurotto`] and [`SimpleAssocSurotto`], and for [`Maybe`].
+///
+/// [`SimpleSurotto`]: crate::simple::SimpleSurotto
+/// [`SimpleAssocSurotto`]: crate::simple_assoc::SimpleAssocSurotto
+pub trait JoinSource<K: SimpleKey> {
+    type Item;
+    /// An iterator yielding the content of every slot in index order.
+    type Slots: Iterator<Item = Option<Self::Item>>;
+
+    fn slots(self) -> Self::Slots;
+}
+
+/// A [`JoinSource`] which can drive a [`join`].
+///
+/// The primary source decides which keys are visited,
+/// as the join creates a key for every occupied slot it yields.
+///
+/// # Safety
+///
+/// [`slots`] must only yield occupied slots at indices of keys which were created before.
+///
+/// [`slots`]: JoinSource::slots
+pub unsafe trait JoinPrimary<K: SimpleKey>: JoinSource<K> {}
+
+/// Wraps a [`JoinSource`] so the join doesn't skip keys it has no value for.
+/// The item type becomes `Option<S::Item>`.
+pub struct Maybe<S>(pub S);
+
+/// Shorthand for [`Maybe`].
+pub fn maybe<S>(source: S) -> Maybe<S> {
+    Maybe(source)
+}
+
+type MaybeSlot<T> = Option<Option<T>>;
+
+type MaybeSlots<T, S> = iter::Chain<
+    iter::Map<S, fn(Option<T>) -> MaybeSlot<T>>,
+    iter::RepeatWith<fn() -> MaybeSlot<T>>,
+>;
+
+impl<K: SimpleKey, S: JoinSource<K>> JoinSource<K> for Maybe<S> {
+    type Item = Option<S::Item>;
+    type Slots = MaybeSlots<S::Item, S::Slots>;
+
+    fn slots(self) -> Self::Slots {
+        let present: fn(Option<S::Item>) -> MaybeSlot<S::Item> = Some;
+        let absent: fn() -> MaybeSlot<S::Item> = || Some(None);
+        self.0.slots().map(present).chain(iter::repeat_with(absent))
+    }
+}
+
+/// A tuple of sources which can be joined,
+/// with a [`JoinPrimary`] first and up to three [`JoinSource`]s after it.
+pub trait Joinable<K: SimpleKey> {
+    type Join: Iterator;
+
+    fn join(self) -> Self::Join;
+}
+
+/// An iterator visiting all keys of the primary source which are present
+/// in all other sources, yielding the key and the item of every source.
+///
+/// The sources are walked side by side, so no key is looked up twice
+/// and mutable borrows of different surottos can be mixed freely.
+pub fn join<K: SimpleKey, J: Joinable<K>>(sources: J) -> J::Join {
+    sources.join()
+}
+
+pub struct Join<K: SimpleKey, P, S> {
+    primary: P,
+    secondary: S,
+    idx: usize,
+    phantom: PhantomData<K>,
+}
+
+macro_rules! impl_join {
+    ($($source:ident $slots:ident),*) => {
+        impl<K, P, $($source),*> Joinable<K> for (P, $($source,)*)
+        where
+            K: SimpleKey,
+            P: JoinPrimary<K>,
+            $($source: JoinSource<K>,)*
+        {
+            type Join = Join<K, P::Slots, ($($source::Slots,)*)>;
+
+            #[allow(non_snake_case)]
+            fn join(self) -> Self::Join {
+                let (primary, $($source,)*) = self;
+                Join {
+                    primary: primary.slots(),
+                    secondary: ($($source.slots(),)*),
+                    idx: 0,
+                    phantom: PhantomData,
+                }
+            }
+        }
+
+        impl<K, P, T, $($source, $slots),*> Iterator for Join<K, P, ($($slots,)*)>
+        where
+            K: SimpleKey,
+            P: Iterator<Item = Option<T>>,
+            $($slots: Iterator<Item = Option<$source>>,)*
+        {
+            type Item = (K, T, $($source,)*);
+
+            #[allow(non_snake_case)]
+            fn next(&mut self) -> Option<Self::Item> {
+                let ($($slots,)*) = &mut self.secondary;
+                loop {
+                    let primary = self.primary.next()?;
+                    $(let $source = $slots.next()?;)*
+                    let i = self.idx;
+                    self.idx += 1;
+                    if let (Some(primary), $(Some($source),)*) = (primary, $($source,)*) {
+                        return Some((
+                            unsafe {
+                                // SAFETY: `JoinPrimary` guarantees that occupied slots of the
+                                //          primary source belong to keys which were created before.
+                                K::new(i)
+                            },
+                            primary,
+                            $($source,)*
+                        ));
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_join!();
+impl_join!(A SA);
+impl_join!(A SA, B SB);
+impl_join!(A SA, B SB, C SC);
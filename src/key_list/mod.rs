@@ -0,0 +1,219 @@
+use crate::{simple::SimpleKey, simple_assoc::SimpleAssocSurotto};
+
+/// A doubly-linked list of keys, e.g. for queues or z-ordered layers over the values
+/// of a surotto.
+///
+/// The links are stored in a [`SimpleAssocSurotto`], so inserting and removing keys
+/// anywhere in the list is O(1) and needs no allocation per element.
+/// Every key can be in the list at most once.
+pub struct KeyList<K: SimpleKey> {
+    links: SimpleAssocSurotto<K, Link<K>>,
+    head: Option<K>,
+    tail: Option<K>,
+    len: usize,
+}
+
+#[derive(Clone, Copy)]
+struct Link<K> {
+    prev: Option<K>,
+    next: Option<K>,
+}
+
+impl<K: SimpleKey> KeyList<K> {
+    /// Constructs a new, empty `KeyList<K>`.
+    pub const fn new() -> Self {
+        Self {
+            links: SimpleAssocSurotto::new(),
+            head: None,
+            tail: None,
+            len: 0,
+        }
+    }
+
+    /// Appends the key to the back of the list.
+    ///
+    /// Returns `false` and does nothing if the key is already in the list.
+    pub fn push_back(&mut self, key: K) -> bool {
+        self.link(key, self.tail, None)
+    }
+
+    /// Prepends the key to the front of the list.
+    ///
+    /// Returns `false` and does nothing if the key is already in the list.
+    pub fn push_front(&mut self, key: K) -> bool {
+        self.link(key, None, self.head)
+    }
+
+    /// Inserts the key directly after `anchor`.
+    ///
+    /// Returns `false` and does nothing if the key is already in the list
+    /// or `anchor` isn't.
+    pub fn insert_after(&mut self, anchor: K, key: K) -> bool {
+        match self.links.get(anchor) {
+            Some(link) => {
+                let next = link.next;
+                self.link(key, Some(anchor), next)
+            }
+            None => false,
+        }
+    }
+
+    /// Inserts the key directly before `anchor`.
+    ///
+    /// Returns `false` and does nothing if the key is already in the list
+    /// or `anchor` isn't.
+    pub fn insert_before(&mut self, anchor: K, key: K) -> bool {
+        match self.links.get(anchor) {
+            Some(link) => {
+                let prev = link.prev;
+                self.link(key, prev, Some(anchor))
+            }
+            None => false,
+        }
+    }
+
+    /// Removes the key from the list, returning `true` if it was in the list.
+    pub fn remove(&mut self, key: K) -> bool {
+        let Some(link) = self.links.remove(key) else {
+            return false;
+        };
+        match link.prev {
+            Some(prev) => self.link_mut(prev).next = link.next,
+            None => self.head = link.next,
+        }
+        match link.next {
+            Some(next) => self.link_mut(next).prev = link.prev,
+            None => self.tail = link.prev,
+        }
+        self.len -= 1;
+        true
+    }
+
+    /// Removes the first key of the list and returns it, or `None` if it is empty.
+    pub fn pop_front(&mut self) -> Option<K> {
+        let key = self.head?;
+        self.remove(key);
+        Some(key)
+    }
+
+    /// Removes the last key of the list and returns it, or `None` if it is empty.
+    pub fn pop_back(&mut self) -> Option<K> {
+        let key = self.tail?;
+        self.remove(key);
+        Some(key)
+    }
+
+    /// Returns the first key of the list.
+    pub fn front(&self) -> Option<K> {
+        self.head
+    }
+
+    /// Returns the last key of the list.
+    pub fn back(&self) -> Option<K> {
+        self.tail
+    }
+
+    /// Returns the key after the key, or `None` if it is the last one or not in the list.
+    pub fn next(&self, key: K) -> Option<K> {
+        self.links.get(key).and_then(|link| link.next)
+    }
+
+    /// Returns the key before the key, or `None` if it is the first one or not in the list.
+    pub fn prev(&self, key: K) -> Option<K> {
+        self.links.get(key).and_then(|link| link.prev)
+    }
+
+    /// Returns `true` if the key is in the list.
+    pub fn contains(&self, key: K) -> bool {
+        self.links.contains_key(key)
+    }
+
+    /// Returns the number of keys in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the list contains no keys.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// An iterator visiting all keys from front to back.
+    /// The iterator element type is `K`.
+    pub fn iter(&self) -> Iter<'_, K> {
+        Iter {
+            list: self,
+            front: self.head,
+            back: self.tail,
+            len: self.len,
+        }
+    }
+
+    fn link(&mut self, key: K, prev: Option<K>, next: Option<K>) -> bool {
+        if self.links.contains_key(key) {
+            return false;
+        }
+        self.links.insert(key, Link { prev, next });
+        match prev {
+            Some(prev) => self.link_mut(prev).next = Some(key),
+            None => self.head = Some(key),
+        }
+        match next {
+            Some(next) => self.link_mut(next).prev = Some(key),
+            None => self.tail = Some(key),
+        }
+        self.len += 1;
+        true
+    }
+
+    fn link_mut(&mut self, key: K) -> &mut Link<K> {
+        self.links
+            .get_mut(key)
+            .expect("linked keys are in the list")
+    }
+}
+
+impl<K: SimpleKey> Default for KeyList<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct Iter<'a, K: SimpleKey> {
+    list: &'a KeyList<K>,
+    front: Option<K>,
+    back: Option<K>,
+    len: usize,
+}
+
+impl<'a, K: SimpleKey> Iterator for Iter<'a, K> {
+    type Item = K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let key = self.front?;
+        self.front = self.list.next(key);
+        self.len -= 1;
+        Some(key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, K: SimpleKey> DoubleEndedIterator for Iter<'a, K> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let key = self.back?;
+        self.back = self.list.prev(key);
+        self.len -= 1;
+        Some(key)
+    }
+}
+
+impl<'a, K: SimpleKey> ExactSizeIterator for Iter<'a, K> {}
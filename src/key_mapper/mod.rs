@@ -0,0 +1,88 @@
+use crate::{key_set::KeySet, simple::SimpleKey, simple_assoc::SimpleAssocSurotto};
+
+/// Records which key of one surotto corresponds to which key of another one.
+///
+/// This is used when a surotto gets rebuilt or replicated, so data keyed by
+/// the old keys can be translated to the new ones.
+pub struct KeyMapper<Old: SimpleKey, New: SimpleKey> {
+    inner: SimpleAssocSurotto<Old, New>,
+}
+
+impl<Old: SimpleKey, New: SimpleKey> KeyMapper<Old, New> {
+    /// Constructs a new, empty `KeyMapper<Old, New>`.
+    pub const fn new() -> Self {
+        Self {
+            inner: SimpleAssocSurotto::new(),
+        }
+    }
+
+    /// Records that `old` now corresponds to `new`.
+    ///
+    /// If `old` was already recorded, the previous key is returned.
+    pub fn insert(&mut self, old: Old, new: New) -> Option<New> {
+        self.inner.insert(old, new)
+    }
+
+    /// Returns the new key corresponding to `old`, if one was recorded.
+    pub fn translate(&self, old: Old) -> Option<New> {
+        self.inner.get(old).copied()
+    }
+
+    /// Applies the mapper to a value holding keys.
+    pub fn apply<R>(&self, value: R) -> R::Output
+    where
+        R: Remappable<Old, New>,
+    {
+        value.remap(self)
+    }
+}
+
+impl<Old: SimpleKey, New: SimpleKey> Default for KeyMapper<Old, New> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A value holding keys which can be translated by a [`KeyMapper`].
+pub trait Remappable<Old: SimpleKey, New: SimpleKey> {
+    /// The value with translated keys.
+    type Output;
+
+    /// Translates all keys in the value with the mapper.
+    fn remap(self, mapper: &KeyMapper<Old, New>) -> Self::Output;
+}
+
+impl<Old: SimpleKey, New: SimpleKey> Remappable<Old, New> for Old {
+    type Output = Option<New>;
+
+    fn remap(self, mapper: &KeyMapper<Old, New>) -> Self::Output {
+        mapper.translate(self)
+    }
+}
+
+impl<Old: SimpleKey, New: SimpleKey, V> Remappable<Old, New> for SimpleAssocSurotto<Old, V> {
+    type Output = SimpleAssocSurotto<New, V>;
+
+    /// Moves all values to their translated keys, dropping those whose keys
+    /// have no translation.
+    fn remap(self, mapper: &KeyMapper<Old, New>) -> Self::Output {
+        let mut surotto = SimpleAssocSurotto::new();
+        for (old, val) in self {
+            if let Some(new) = mapper.translate(old) {
+                surotto.insert(new, val);
+            }
+        }
+        surotto
+    }
+}
+
+impl<Old: SimpleKey, New: SimpleKey> Remappable<Old, New> for KeySet<Old> {
+    type Output = KeySet<New>;
+
+    /// Translates all keys, dropping those which have no translation.
+    fn remap(self, mapper: &KeyMapper<Old, New>) -> Self::Output {
+        self.iter()
+            .filter_map(|old| mapper.translate(old))
+            .collect()
+    }
+}
@@ -0,0 +1,188 @@
+use std::{iter, marker::PhantomData, slice};
+
+use crate::simple::SimpleKey;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A set of keys, stored as one bit per key index.
+///
+/// Inserting, removing and checking keys is O(1), and set operations work
+/// on 64 keys at once. The set grows to the highest key index ever inserted.
+#[derive(Clone)]
+pub struct KeySet<K: SimpleKey> {
+    words: Vec<u64>,
+    len: usize,
+    phantom: PhantomData<K>,
+}
+
+impl<K: SimpleKey> KeySet<K> {
+    /// Constructs a new, empty `KeySet<K>`.
+    pub const fn new() -> Self {
+        Self {
+            words: Vec::new(),
+            len: 0,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Adds the key to the set, returning `true` if it wasn't present before.
+    pub fn insert(&mut self, key: K) -> bool {
+        let (word, mask) = location(key.idx());
+        if word >= self.words.len() {
+            self.words.resize(word + 1, 0);
+        }
+        let present = self.words[word] & mask != 0;
+        self.words[word] |= mask;
+        if !present {
+            self.len += 1;
+        }
+        !present
+    }
+
+    /// Removes the key from the set, returning `true` if it was present.
+    pub fn remove(&mut self, key: K) -> bool {
+        let (word, mask) = location(key.idx());
+        match self.words.get_mut(word) {
+            Some(bits) if *bits & mask != 0 => {
+                *bits &= !mask;
+                self.len -= 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the set contains the key.
+    pub fn contains(&self, key: K) -> bool {
+        let (word, mask) = location(key.idx());
+        self.words.get(word).is_some_and(|bits| bits & mask != 0)
+    }
+
+    /// Removes all keys, keeping the allocated memory.
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+        self.len = 0;
+    }
+
+    /// Returns the number of keys in the set.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the set contains no keys.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Adds all keys of `other` to the set.
+    pub fn union_with(&mut self, other: &Self) {
+        if other.words.len() > self.words.len() {
+            self.words.resize(other.words.len(), 0);
+        }
+        for (bits, other) in self.words.iter_mut().zip(&other.words) {
+            *bits |= other;
+        }
+        self.recount();
+    }
+
+    /// Removes all keys which aren't in `other` from the set.
+    pub fn intersect_with(&mut self, other: &Self) {
+        self.words.truncate(other.words.len());
+        for (bits, other) in self.words.iter_mut().zip(&other.words) {
+            *bits &= other;
+        }
+        self.recount();
+    }
+
+    /// Removes all keys which are in `other` from the set.
+    pub fn difference_with(&mut self, other: &Self) {
+        for (bits, other) in self.words.iter_mut().zip(&other.words) {
+            *bits &= !other;
+        }
+        self.recount();
+    }
+
+    /// An iterator visiting all keys in index order.
+    /// The iterator element type is `K`.
+    pub fn iter(&self) -> Iter<'_, K> {
+        Iter {
+            words: self.words.iter().enumerate(),
+            current: None,
+            remaining: self.len,
+            phantom: PhantomData,
+        }
+    }
+
+    fn recount(&mut self) {
+        self.len = self
+            .words
+            .iter()
+            .map(|bits| bits.count_ones() as usize)
+            .sum();
+    }
+}
+
+impl<K: SimpleKey> Default for KeySet<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: SimpleKey> Extend<K> for KeySet<K> {
+    fn extend<I: IntoIterator<Item = K>>(&mut self, iter: I) {
+        for key in iter {
+            self.insert(key);
+        }
+    }
+}
+
+impl<K: SimpleKey> FromIterator<K> for KeySet<K> {
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+/// Returns the word and the bit mask inside of it for the index.
+fn location(idx: usize) -> (usize, u64) {
+    (idx / WORD_BITS, 1 << (idx % WORD_BITS))
+}
+
+pub struct Iter<'a, K: SimpleKey> {
+    words: iter::Enumerate<slice::Iter<'a, u64>>,
+    /// The word index and the bits of it which weren't visited yet.
+    current: Option<(usize, u64)>,
+    remaining: usize,
+    phantom: PhantomData<K>,
+}
+
+impl<'a, K: SimpleKey> Iterator for Iter<'a, K> {
+    type Item = K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match &mut self.current {
+                Some((word, bits)) if *bits != 0 => {
+                    let idx = *word * WORD_BITS + bits.trailing_zeros() as usize;
+                    *bits &= *bits - 1;
+                    self.remaining -= 1;
+                    return Some(unsafe {
+                        // SAFETY: Only indices of keys which were created before are inserted.
+                        K::new(idx)
+                    });
+                }
+                _ => self.current = self.words.next().map(|(word, &bits)| (word, bits)),
+            }
+            self.current?;
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, K: SimpleKey> ExactSizeIterator for Iter<'a, K> {}
+
+impl<'a, K: SimpleKey> iter::FusedIterator for Iter<'a, K> {}
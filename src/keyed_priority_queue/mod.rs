@@ -0,0 +1,147 @@
+use std::mem;
+
+use crate::{simple::SimpleKey, simple_assoc::SimpleAssocSurotto};
+
+/// A priority queue of keys, where every key has at most one priority.
+///
+/// The queue is a max-heap like [`BinaryHeap`]; wrap the priorities in [`Reverse`]
+/// to pop the minimum first. The heap position of every key is tracked in a
+/// [`SimpleAssocSurotto`], so keys can be located in O(1) for changing their
+/// priority or removing them.
+///
+/// [`BinaryHeap`]: std::collections::BinaryHeap
+/// [`Reverse`]: std::cmp::Reverse
+pub struct KeyedPriorityQueue<K: SimpleKey, P: Ord> {
+    heap: Vec<(K, P)>,
+    positions: SimpleAssocSurotto<K, usize>,
+}
+
+impl<K: SimpleKey, P: Ord> KeyedPriorityQueue<K, P> {
+    /// Constructs a new, empty `KeyedPriorityQueue<K, P>`.
+    pub const fn new() -> Self {
+        Self {
+            heap: Vec::new(),
+            positions: SimpleAssocSurotto::new(),
+        }
+    }
+
+    /// Pushes the key with the priority into the queue.
+    ///
+    /// If the key was already queued, its priority is changed and the old
+    /// priority is returned.
+    pub fn push(&mut self, key: K, priority: P) -> Option<P> {
+        if self.contains_key(key) {
+            return self.change_priority(key, priority);
+        }
+
+        let pos = self.heap.len();
+        self.heap.push((key, priority));
+        self.positions.insert(key, pos);
+        self.sift_up(pos);
+        None
+    }
+
+    /// Removes the key with the greatest priority from the queue and returns it
+    /// together with its priority, or `None` if it is empty.
+    pub fn pop(&mut self) -> Option<(K, P)> {
+        let key = self.heap.first()?.0;
+        self.remove(key).map(|priority| (key, priority))
+    }
+
+    /// Returns the key with the greatest priority together with its priority,
+    /// or `None` if the queue is empty.
+    pub fn peek(&self) -> Option<(K, &P)> {
+        self.heap.first().map(|(key, priority)| (*key, priority))
+    }
+
+    /// Changes the priority of a queued key, returning the old priority.
+    ///
+    /// Returns `None` and does nothing if the key isn't queued.
+    pub fn change_priority(&mut self, key: K, priority: P) -> Option<P> {
+        let pos = *self.positions.get(key)?;
+        let old = mem::replace(&mut self.heap[pos].1, priority);
+        self.restore(pos);
+        Some(old)
+    }
+
+    /// Removes the key from the queue, returning its priority if it was queued.
+    pub fn remove(&mut self, key: K) -> Option<P> {
+        let pos = self.positions.remove(key)?;
+        let (_, priority) = self.heap.swap_remove(pos);
+        if pos < self.heap.len() {
+            self.positions.insert(self.heap[pos].0, pos);
+            self.restore(pos);
+        }
+        Some(priority)
+    }
+
+    /// Returns the priority of the key, if it is queued.
+    pub fn priority(&self, key: K) -> Option<&P> {
+        self.positions.get(key).map(|&pos| &self.heap[pos].1)
+    }
+
+    /// Returns true if the key is queued.
+    pub fn contains_key(&self, key: K) -> bool {
+        self.positions.contains_key(key)
+    }
+
+    /// Returns true if the queue contains no keys.
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Returns the number of keys in the queue.
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    fn restore(&mut self, pos: usize) {
+        if self.sift_up(pos) == pos {
+            self.sift_down(pos);
+        }
+    }
+
+    fn sift_up(&mut self, mut pos: usize) -> usize {
+        while pos > 0 {
+            let parent = (pos - 1) / 2;
+            if self.heap[pos].1 <= self.heap[parent].1 {
+                break;
+            }
+            self.swap(pos, parent);
+            pos = parent;
+        }
+        pos
+    }
+
+    fn sift_down(&mut self, mut pos: usize) {
+        loop {
+            let left = 2 * pos + 1;
+            if left >= self.heap.len() {
+                break;
+            }
+            let right = left + 1;
+            let child = if right < self.heap.len() && self.heap[right].1 > self.heap[left].1 {
+                right
+            } else {
+                left
+            };
+            if self.heap[child].1 <= self.heap[pos].1 {
+                break;
+            }
+            self.swap(pos, child);
+            pos = child;
+        }
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        self.positions.insert(self.heap[a].0, a);
+        self.positions.insert(self.heap[b].0, b);
+    }
+}
+
+impl<K: SimpleKey, P: Ord> Default for KeyedPriorityQueue<K, P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -6,5 +6,18 @@
     broken_intra_doc_links
 )]
 
+pub mod hierarchy;
+pub mod indexed;
+pub mod join;
+pub mod key_list;
+pub mod key_mapper;
+pub mod key_set;
+pub mod keyed_priority_queue;
 pub mod simple;
 pub mod simple_assoc;
+pub mod simple_concurrent;
+pub mod tracked;
+pub mod typed_storage;
+pub mod union_find;
+
+mod unchecked;
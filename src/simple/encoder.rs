@@ -0,0 +1,64 @@
+use std::{collections::hash_map::RandomState, hash::BuildHasher, marker::PhantomData};
+
+use super::{SimpleKey, SimpleSurotto};
+
+/// Encodes keys into opaque `u64` handles which can be exposed to untrusted clients.
+///
+/// Every encoder is seeded randomly, so each handle carries a tag only this encoder
+/// can produce. Decoding rejects handles which were forged or come from another encoder.
+///
+/// # Important
+///
+/// The tag consists of 32 bits of a keyed hash. It makes guessing handles impractical,
+/// but it is no cryptographic MAC.
+pub struct KeyEncoder<K: SimpleKey> {
+    state: RandomState,
+    phantom: PhantomData<K>,
+}
+
+impl<K: SimpleKey> KeyEncoder<K> {
+    /// Constructs a new, randomly seeded `KeyEncoder<K>`.
+    pub fn new() -> Self {
+        Self {
+            state: RandomState::new(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Encodes the key into a handle, with the index in the low 32 bits
+    /// and the tag in the high 32 bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the key index doesn't fit into 32 bits.
+    pub fn encode(&self, key: K) -> u64 {
+        let idx = u32::try_from(key.idx()).expect("key index doesn't fit into 32 bits");
+        (u64::from(self.tag(idx)) << 32) | u64::from(idx)
+    }
+
+    /// Decodes a handle back into its key.
+    ///
+    /// Returns `None` when the handle wasn't created by this encoder
+    /// or the surotto doesn't contain its index.
+    pub fn decode<V>(&self, surotto: &SimpleSurotto<K, V>, handle: u64) -> Option<K> {
+        let idx = handle as u32;
+        if (handle >> 32) as u32 != self.tag(idx) || idx as usize >= surotto.len() {
+            return None;
+        }
+
+        Some(unsafe {
+            // SAFETY: We just checked that the surotto contains the index.
+            K::new(idx as usize)
+        })
+    }
+
+    fn tag(&self, idx: u32) -> u32 {
+        self.state.hash_one(idx) as u32
+    }
+}
+
+impl<K: SimpleKey> Default for KeyEncoder<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -22,8 +22,31 @@ impl<'a, K: SimpleKey, V> Iterator for Iter<'a, K, V> {
             )
         })
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
 }
 
+impl<'a, K: SimpleKey, V> DoubleEndedIterator for Iter<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(i, val)| {
+            (
+                unsafe {
+                    // SAFETY: The iterator only returns elements which are present and
+                    //          elements can't be removed, thus the creation of the key is safe here.
+                    K::new(i)
+                },
+                val,
+            )
+        })
+    }
+}
+
+impl<'a, K: SimpleKey, V> ExactSizeIterator for Iter<'a, K, V> {}
+
+impl<'a, K: SimpleKey, V> iter::FusedIterator for Iter<'a, K, V> {}
+
 pub struct IterMut<'a, K: SimpleKey, V> {
     pub(super) inner: iter::Enumerate<core::slice::IterMut<'a, V>>,
     pub(super) phantom: PhantomData<K>,
@@ -44,8 +67,31 @@ impl<'a, K: SimpleKey, V> Iterator for IterMut<'a, K, V> {
             )
         })
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, K: SimpleKey, V> DoubleEndedIterator for IterMut<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(i, val)| {
+            (
+                unsafe {
+                    // SAFETY: The iterator only returns elements which are present and
+                    //          elements can't be removed, thus the creation of the key is safe here.
+                    K::new(i)
+                },
+                val,
+            )
+        })
+    }
 }
 
+impl<'a, K: SimpleKey, V> ExactSizeIterator for IterMut<'a, K, V> {}
+
+impl<'a, K: SimpleKey, V> iter::FusedIterator for IterMut<'a, K, V> {}
+
 pub struct IntoIter<K: SimpleKey, V> {
     pub(super) inner: iter::Enumerate<std::vec::IntoIter<V>>,
     pub(super) phantom: PhantomData<K>,
@@ -66,8 +112,31 @@ impl<K: SimpleKey, V> Iterator for IntoIter<K, V> {
             )
         })
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
 }
 
+impl<K: SimpleKey, V> DoubleEndedIterator for IntoIter<K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(i, val)| {
+            (
+                unsafe {
+                    // SAFETY: The iterator only returns elements which are present and
+                    //          elements can't be removed, thus the creation of the key is safe here.
+                    K::new(i)
+                },
+                val,
+            )
+        })
+    }
+}
+
+impl<K: SimpleKey, V> ExactSizeIterator for IntoIter<K, V> {}
+
+impl<K: SimpleKey, V> iter::FusedIterator for IntoIter<K, V> {}
+
 pub struct Keys<'a, K: SimpleKey, V> {
     pub(super) inner: Iter<'a, K, V>,
 }
@@ -78,8 +147,22 @@ impl<'a, K: SimpleKey, V> Iterator for Keys<'a, K, V> {
     fn next(&mut self) -> Option<Self::Item> {
         self.inner.next().map(|(key, _)| key)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
 }
 
+impl<'a, K: SimpleKey, V> DoubleEndedIterator for Keys<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(key, _)| key)
+    }
+}
+
+impl<'a, K: SimpleKey, V> ExactSizeIterator for Keys<'a, K, V> {}
+
+impl<'a, K: SimpleKey, V> iter::FusedIterator for Keys<'a, K, V> {}
+
 pub struct Values<'a, K: SimpleKey, V> {
     pub(super) inner: Iter<'a, K, V>,
 }
@@ -90,8 +173,22 @@ impl<'a, K: SimpleKey, V> Iterator for Values<'a, K, V> {
     fn next(&mut self) -> Option<Self::Item> {
         self.inner.next().map(|(_, val)| val)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
 }
 
+impl<'a, K: SimpleKey, V> DoubleEndedIterator for Values<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(_, val)| val)
+    }
+}
+
+impl<'a, K: SimpleKey, V> ExactSizeIterator for Values<'a, K, V> {}
+
+impl<'a, K: SimpleKey, V> iter::FusedIterator for Values<'a, K, V> {}
+
 pub struct ValuesMut<'a, K: SimpleKey, V> {
     pub(super) inner: IterMut<'a, K, V>,
 }
@@ -102,4 +199,70 @@ impl<'a, K: SimpleKey, V> Iterator for ValuesMut<'a, K, V> {
     fn next(&mut self) -> Option<Self::Item> {
         self.inner.next().map(|(_, val)| val)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, K: SimpleKey, V> DoubleEndedIterator for ValuesMut<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(_, val)| val)
+    }
+}
+
+impl<'a, K: SimpleKey, V> ExactSizeIterator for ValuesMut<'a, K, V> {}
+
+impl<'a, K: SimpleKey, V> iter::FusedIterator for ValuesMut<'a, K, V> {}
+
+pub struct IntoKeys<K: SimpleKey, V> {
+    pub(super) inner: IntoIter<K, V>,
+}
+
+impl<K: SimpleKey, V> Iterator for IntoKeys<K, V> {
+    type Item = K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(key, _)| key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K: SimpleKey, V> DoubleEndedIterator for IntoKeys<K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(key, _)| key)
+    }
+}
+
+impl<K: SimpleKey, V> ExactSizeIterator for IntoKeys<K, V> {}
+
+impl<K: SimpleKey, V> iter::FusedIterator for IntoKeys<K, V> {}
+
+pub struct IntoValues<K: SimpleKey, V> {
+    pub(super) inner: IntoIter<K, V>,
+}
+
+impl<K: SimpleKey, V> Iterator for IntoValues<K, V> {
+    type Item = V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, val)| val)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K: SimpleKey, V> DoubleEndedIterator for IntoValues<K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(_, val)| val)
+    }
 }
+
+impl<K: SimpleKey, V> ExactSizeIterator for IntoValues<K, V> {}
+
+impl<K: SimpleKey, V> iter::FusedIterator for IntoValues<K, V> {}
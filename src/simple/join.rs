@@ -0,0 +1,29 @@
+use std::{iter, slice};
+
+use crate::join::{JoinPrimary, JoinSource};
+
+use super::{SimpleKey, SimpleSurotto};
+
+impl<'a, K: SimpleKey, V> JoinSource<K> for &'a SimpleSurotto<K, V> {
+    type Item = &'a V;
+    type Slots = iter::Map<slice::Iter<'a, V>, fn(&'a V) -> Option<&'a V>>;
+
+    fn slots(self) -> Self::Slots {
+        self.inner.iter().map(Some)
+    }
+}
+
+// SAFETY: Every slot of the surotto belongs to a key it created.
+unsafe impl<K: SimpleKey, V> JoinPrimary<K> for &SimpleSurotto<K, V> {}
+
+impl<'a, K: SimpleKey, V> JoinSource<K> for &'a mut SimpleSurotto<K, V> {
+    type Item = &'a mut V;
+    type Slots = iter::Map<slice::IterMut<'a, V>, fn(&'a mut V) -> Option<&'a mut V>>;
+
+    fn slots(self) -> Self::Slots {
+        self.inner.iter_mut().map(Some)
+    }
+}
+
+// SAFETY: Every slot of the surotto belongs to a key it created.
+unsafe impl<K: SimpleKey, V> JoinPrimary<K> for &mut SimpleSurotto<K, V> {}
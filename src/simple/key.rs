@@ -1,5 +1,17 @@
 pub type SimpleKeyData = nonmax::NonMaxUsize;
 
+/// A key which can be used to index into a [`SimpleSurotto`].
+///
+/// # Safety
+///
+/// A key may only be created by the surotto it belongs to, and `idx` must return
+/// the same index the key was created with.
+///
+/// A key type belongs to exactly one surotto instance of any kind, e.g. a key type
+/// used by a [`SimpleConcurrentSurotto`] must not also be used by a [`SimpleSurotto`].
+///
+/// [`SimpleSurotto`]: crate::simple::SimpleSurotto
+/// [`SimpleConcurrentSurotto`]: crate::simple_concurrent::SimpleConcurrentSurotto
 pub unsafe trait SimpleKey: Sized + Clone + Copy {
     /// Creates a new key from usize
     ///
@@ -14,6 +26,28 @@ pub unsafe trait SimpleKey: Sized + Clone + Copy {
     fn idx(self) -> usize;
 }
 
+/// Packs the key into a `u64`, e.g. to pass it across an FFI boundary.
+///
+/// This is a stable wire format: the index is stored in the low bits
+/// and all remaining bits are zero.
+pub fn key_to_bits<K: SimpleKey>(key: K) -> u64 {
+    key.idx() as u64
+}
+
+/// Unpacks a key previously packed with [`key_to_bits`].
+///
+/// # Panics
+///
+/// Panics when `bits` doesn't fit into a `usize`.
+///
+/// # Safety
+///
+/// `bits` must come from [`key_to_bits`] of a key of the same surotto,
+/// the same rules as for [`SimpleKey::new`] apply.
+pub unsafe fn key_from_bits<K: SimpleKey>(bits: u64) -> K {
+    K::new(usize::try_from(bits).expect("key bits don't fit into usize"))
+}
+
 #[macro_export]
 macro_rules! simple_key {
     ($vis:vis struct $name:ident;) => {
@@ -0,0 +1,19 @@
+/// Creates a [`SimpleSurotto`] containing the given values.
+///
+/// `simple_surotto![of Key; a, b, c]` evaluates to the surotto, while
+/// `simple_surotto! { let surotto of Key; a => x, b => y }` binds the surotto
+/// and the keys of the inserted values to the given names.
+///
+/// [`SimpleSurotto`]: crate::simple::SimpleSurotto
+#[macro_export]
+macro_rules! simple_surotto {
+    (let $surotto:ident of $key:ty; $($name:ident => $value:expr),* $(,)?) => {
+        let mut $surotto = $crate::simple::SimpleSurotto::<$key, _>::new();
+        $(let $name = $surotto.insert($value);)*
+    };
+    (of $key:ty; $($value:expr),* $(,)?) => {{
+        let mut surotto = $crate::simple::SimpleSurotto::<$key, _>::new();
+        $(surotto.insert($value);)*
+        surotto
+    }};
+}
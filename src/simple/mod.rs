@@ -1,20 +1,41 @@
 use std::{
     collections::TryReserveError,
+    fmt,
     marker::PhantomData,
-    ops::{Index, IndexMut},
+    mem,
+    ops::{Index, IndexMut, Range},
 };
 
+use crate::{key_mapper::KeyMapper, unchecked};
+
 pub mod iterators;
-use self::iterators::{IntoIter, Iter, IterMut, Keys, Values, ValuesMut};
+use self::iterators::{IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, Values, ValuesMut};
 
 mod key;
 pub use self::key::*;
 
+mod encoder;
+pub use self::encoder::*;
+
+mod join;
+
+mod macros;
+
+#[cfg(feature = "rayon")]
+pub mod rayon;
+
+#[cfg(feature = "serde")]
+mod serde;
+
+#[cfg(feature = "rand")]
+mod rand;
+
 /// A datastructure where values can only be inserted, returning a typed key.
 ///
 /// # Important
 ///
-/// The key type must be unique to this and only this surotto.
+/// The key type must be unique to this and only this surotto, however it was created,
+/// e.g. with [`new`](Self::new) or by deserializing it.
 /// This is required for safely getting values without `Option`s.
 /// Associated surottos are still allowed tho, because they don't create any keys.
 pub struct SimpleSurotto<K: SimpleKey, V> {
@@ -77,12 +98,87 @@ impl<K: SimpleKey, V> SimpleSurotto<K, V> {
         key
     }
 
+    /// Inserts all values of the iterator into the surotto.
+    ///
+    /// # Errors
+    ///
+    /// If the iterator yields an error, all values inserted so far are removed
+    /// again and the error is returned.
+    pub fn try_extend<I, E>(&mut self, iter: I) -> Result<(), E>
+    where
+        I: IntoIterator<Item = Result<V, E>>,
+    {
+        let start = self.inner.len();
+        for value in iter {
+            match value {
+                Ok(value) => self.inner.push(value),
+                Err(err) => {
+                    self.inner.truncate(start);
+                    return Err(err);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Inserts all values of the iterator into the surotto, returning their keys.
+    ///
+    /// # Errors
+    ///
+    /// If the iterator yields an error, all values inserted so far are removed
+    /// again and the error is returned.
+    pub fn try_insert_batch<I, E>(&mut self, iter: I) -> Result<Vec<K>, E>
+    where
+        I: IntoIterator<Item = Result<V, E>>,
+    {
+        let start = self.inner.len();
+        self.try_extend(iter)?;
+        Ok((start..self.inner.len())
+            .map(|i| unsafe {
+                // SAFETY: the values were just inserted and can't be removed anymore.
+                K::new(i)
+            })
+            .collect())
+    }
+
+    /// Moves all values of `other` into the surotto, returning a [`KeyMapper`]
+    /// which translates the keys of `other` to their new keys.
+    ///
+    /// # Panics
+    ///
+    /// Panics when a new key index is `usize::MAX`.
+    pub fn append<K2: SimpleKey>(&mut self, other: SimpleSurotto<K2, V>) -> KeyMapper<K2, K> {
+        let mut mapper = KeyMapper::new();
+        self.append_with(other, |old, new| {
+            mapper.insert(old, new);
+        });
+        mapper
+    }
+
+    /// Moves all values of `other` into the surotto,
+    /// calling the closure with the old and new key of every value.
+    ///
+    /// # Panics
+    ///
+    /// Panics when a new key index is `usize::MAX`.
+    pub fn append_with<K2, F>(&mut self, other: SimpleSurotto<K2, V>, mut f: F)
+    where
+        K2: SimpleKey,
+        F: FnMut(K2, K),
+    {
+        self.inner.reserve(other.len());
+        for (old, value) in other {
+            let new = self.insert(value);
+            f(old, new);
+        }
+    }
+
     /// Returns a reference to the value corresponding to the key.
     pub fn get(&self, key: K) -> &V {
         unsafe {
             // SAFETY: The caller assures that the keys are only from this surotto.
             //          Hence only we create valid keys and can't remove any value, it's safe.
-            self.inner.get_unchecked(key.idx())
+            unchecked::get(&self.inner, key.idx())
         }
     }
 
@@ -91,10 +187,61 @@ impl<K: SimpleKey, V> SimpleSurotto<K, V> {
         unsafe {
             // SAFETY: The caller assures that the keys are only from this surotto.
             //          Hence only we create valid keys and can't remove any value, it's safe.
-            self.inner.get_unchecked_mut(key.idx())
+            unchecked::get_mut(&mut self.inner, key.idx())
         }
     }
 
+    /// Replaces the value corresponding to the key, returning the old value.
+    pub fn replace(&mut self, key: K, value: V) -> V {
+        mem::replace(self.get_mut(key), value)
+    }
+
+    /// Swaps the values of two keys. The keys keep pointing at their slots.
+    pub fn swap(&mut self, a: K, b: K) {
+        self.inner.swap(a.idx(), b.idx());
+    }
+
+    /// Returns the key and value at the index, or `None` if it is out of bounds.
+    pub fn get_by_index(&self, idx: usize) -> Option<(K, &V)> {
+        self.inner.get(idx).map(|val| {
+            (
+                unsafe {
+                    // SAFETY: The value is present and values can't be removed.
+                    K::new(idx)
+                },
+                val,
+            )
+        })
+    }
+
+    /// Returns the key and a mutable reference to the value at the index,
+    /// or `None` if it is out of bounds.
+    pub fn get_by_index_mut(&mut self, idx: usize) -> Option<(K, &mut V)> {
+        self.inner.get_mut(idx).map(|val| {
+            (
+                unsafe {
+                    // SAFETY: The value is present and values can't be removed.
+                    K::new(idx)
+                },
+                val,
+            )
+        })
+    }
+
+    /// Returns mutable references to the values of all given keys at once.
+    ///
+    /// Returns `None` if any key occurs more than once.
+    pub fn get_disjoint_mut_slice(&mut self, keys: &[K]) -> Option<Vec<&mut V>> {
+        if has_duplicates(keys) {
+            return None;
+        }
+        Some(unsafe {
+            // SAFETY: The keys are only from this surotto and we checked they are distinct,
+            //          thus the references are valid and don't alias.
+            unchecked::get_disjoint_mut(&mut self.inner, keys.iter().map(|key| key.idx()))
+        })
+    }
+
     /// Returns true if the surotto contains no elements.
     pub fn is_empty(&self) -> bool {
         self.inner.is_empty()
@@ -232,6 +379,20 @@ impl<K: SimpleKey, V> SimpleSurotto<K, V> {
         }
     }
 
+    /// An iterator visiting all key-value pairs whose key index lies in the range.
+    /// The iterator element type is `(K, &'a V)`.
+    pub fn iter_index_range(&self, range: Range<usize>) -> Iter<'_, K, V> {
+        let end = range.end.min(self.inner.len());
+        let mut inner = self.inner[..end].iter().enumerate();
+        if range.start > 0 {
+            inner.nth(range.start - 1);
+        }
+        Iter {
+            inner,
+            phantom: PhantomData,
+        }
+    }
+
     /// An iterator visiting all key-value pairs,
     /// with mutable references to the values.
     /// The iterator element type is `(K, &'a mut V)`.
@@ -261,8 +422,61 @@ impl<K: SimpleKey, V> SimpleSurotto<K, V> {
             inner: self.iter_mut(),
         }
     }
+
+    /// Creates a consuming iterator visiting all keys.
+    /// The surotto cannot be used after calling this.
+    /// The iterator element type is `K`.
+    pub fn into_keys(self) -> IntoKeys<K, V> {
+        IntoKeys {
+            inner: self.into_iter(),
+        }
+    }
+
+    /// Creates a consuming iterator visiting all values.
+    /// The surotto cannot be used after calling this.
+    /// The iterator element type is `V`.
+    pub fn into_values(self) -> IntoValues<K, V> {
+        IntoValues {
+            inner: self.into_iter(),
+        }
+    }
+
+    /// Calls a closure on every key-value pair, with mutable references to the values.
+    ///
+    /// Unlike going through [`iter_mut`], this is a plain internal loop,
+    /// which usually optimizes better for small closures.
+    ///
+    /// [`iter_mut`]: Self::iter_mut
+    pub fn for_each_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(K, &mut V),
+    {
+        for (i, val) in self.inner.iter_mut().enumerate() {
+            f(
+                unsafe {
+                    // SAFETY: the key is present in the surotto
+                    K::new(i)
+                },
+                val,
+            );
+        }
+    }
 }
 
+impl<K: SimpleKey + fmt::Debug, V: fmt::Debug> fmt::Debug for SimpleSurotto<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: SimpleKey, V: PartialEq> PartialEq for SimpleSurotto<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<K: SimpleKey, V: Eq> Eq for SimpleSurotto<K, V> {}
+
 impl<K: SimpleKey, V> Default for SimpleSurotto<K, V> {
     fn default() -> Self {
         Self::new()
@@ -294,3 +508,9 @@ impl<K: SimpleKey, V> IntoIterator for SimpleSurotto<K, V> {
         }
     }
 }
+
+pub(crate) fn has_duplicates<K: SimpleKey>(keys: &[K]) -> bool {
+    let mut indices: Vec<usize> = keys.iter().map(|key| key.idx()).collect();
+    indices.sort_unstable();
+    indices.windows(2).any(|pair| pair[0] == pair[1])
+}
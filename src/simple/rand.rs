@@ -0,0 +1,30 @@
+use rand::{seq::index, Rng};
+
+use super::{SimpleKey, SimpleSurotto};
+
+impl<K: SimpleKey, V> SimpleSurotto<K, V> {
+    /// Returns a uniformly random key, or `None` if the surotto is empty.
+    pub fn random_key<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<K> {
+        if self.inner.is_empty() {
+            return None;
+        }
+        let idx = rng.gen_range(0..self.inner.len());
+        Some(unsafe {
+            // SAFETY: The index is in bounds and values can't be removed.
+            K::new(idx)
+        })
+    }
+
+    /// Returns `amount` distinct, uniformly random keys in random order,
+    /// or all keys if the surotto contains fewer.
+    pub fn sample_keys<R: Rng + ?Sized>(&self, rng: &mut R, amount: usize) -> Vec<K> {
+        let amount = amount.min(self.inner.len());
+        index::sample(rng, self.inner.len(), amount)
+            .into_iter()
+            .map(|idx| unsafe {
+                // SAFETY: The index is in bounds and values can't be removed.
+                K::new(idx)
+            })
+            .collect()
+    }
+}
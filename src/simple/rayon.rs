@@ -0,0 +1,222 @@
+use std::marker::PhantomData;
+
+use rayon::{
+    iter::{
+        plumbing::UnindexedConsumer, FromParallelIterator, IndexedParallelIterator,
+        IntoParallelIterator, IntoParallelRefIterator, IntoParallelRefMutIterator, ParallelExtend,
+        ParallelIterator,
+    },
+    slice, vec,
+};
+
+use super::{SimpleKey, SimpleSurotto};
+
+impl<K: SimpleKey, V> SimpleSurotto<K, V> {
+    /// A parallel iterator visiting all key-value pairs.
+    /// The iterator element type is `(K, &'a V)`.
+    pub fn par_iter(&self) -> ParIter<'_, K, V>
+    where
+        V: Sync,
+    {
+        ParIter {
+            inner: self.inner.par_iter(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// A parallel iterator visiting all key-value pairs,
+    /// with mutable references to the values.
+    /// The iterator element type is `(K, &'a mut V)`.
+    pub fn par_iter_mut(&mut self) -> ParIterMut<'_, K, V>
+    where
+        V: Send,
+    {
+        ParIterMut {
+            inner: self.inner.par_iter_mut(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// A parallel iterator visiting all values mutably.
+    /// The iterator element type is `&'a mut V`.
+    pub fn par_values_mut(&mut self) -> ParValuesMut<'_, K, V>
+    where
+        V: Send,
+    {
+        ParValuesMut {
+            inner: self.inner.par_iter_mut(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+pub struct ParIter<'a, K: SimpleKey, V: Sync> {
+    inner: slice::Iter<'a, V>,
+    phantom: PhantomData<K>,
+}
+
+impl<'a, K: SimpleKey + Send, V: Sync> ParallelIterator for ParIter<'a, K, V> {
+    type Item = (K, &'a V);
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        self.inner
+            .enumerate()
+            .map(|(i, val)| {
+                (
+                    unsafe {
+                        // SAFETY: The iterator only returns elements which are present and
+                        //          elements can't be removed, thus the creation of the key is safe here.
+                        K::new(i)
+                    },
+                    val,
+                )
+            })
+            .drive_unindexed(consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.inner.len())
+    }
+}
+
+pub struct ParIterMut<'a, K: SimpleKey, V: Send> {
+    inner: slice::IterMut<'a, V>,
+    phantom: PhantomData<K>,
+}
+
+impl<'a, K: SimpleKey + Send, V: Send> ParallelIterator for ParIterMut<'a, K, V> {
+    type Item = (K, &'a mut V);
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        self.inner
+            .enumerate()
+            .map(|(i, val)| {
+                (
+                    unsafe {
+                        // SAFETY: The iterator only returns elements which are present and
+                        //          elements can't be removed, thus the creation of the key is safe here.
+                        K::new(i)
+                    },
+                    val,
+                )
+            })
+            .drive_unindexed(consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.inner.len())
+    }
+}
+
+pub struct ParValuesMut<'a, K: SimpleKey, V: Send> {
+    inner: slice::IterMut<'a, V>,
+    phantom: PhantomData<K>,
+}
+
+impl<'a, K: SimpleKey + Send, V: Send> ParallelIterator for ParValuesMut<'a, K, V> {
+    type Item = &'a mut V;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        self.inner.drive_unindexed(consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.inner.len())
+    }
+}
+
+pub struct ParIntoIter<K: SimpleKey, V: Send> {
+    inner: vec::IntoIter<V>,
+    phantom: PhantomData<K>,
+}
+
+impl<K: SimpleKey + Send, V: Send> ParallelIterator for ParIntoIter<K, V> {
+    type Item = (K, V);
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        self.inner
+            .enumerate()
+            .map(|(i, val)| {
+                (
+                    unsafe {
+                        // SAFETY: The iterator only returns elements which are present and
+                        //          elements can't be removed, thus the creation of the key is safe here.
+                        K::new(i)
+                    },
+                    val,
+                )
+            })
+            .drive_unindexed(consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.inner.len())
+    }
+}
+
+impl<K: SimpleKey + Send, V: Send> IntoParallelIterator for SimpleSurotto<K, V> {
+    type Iter = ParIntoIter<K, V>;
+    type Item = (K, V);
+
+    fn into_par_iter(self) -> Self::Iter {
+        ParIntoIter {
+            inner: self.inner.into_par_iter(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, K: SimpleKey + Send, V: Sync> IntoParallelIterator for &'a SimpleSurotto<K, V> {
+    type Iter = ParIter<'a, K, V>;
+    type Item = (K, &'a V);
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.par_iter()
+    }
+}
+
+impl<'a, K: SimpleKey + Send, V: Send> IntoParallelIterator for &'a mut SimpleSurotto<K, V> {
+    type Iter = ParIterMut<'a, K, V>;
+    type Item = (K, &'a mut V);
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.par_iter_mut()
+    }
+}
+
+impl<K: SimpleKey, V: Send> ParallelExtend<V> for SimpleSurotto<K, V> {
+    /// Extends the surotto with the values of a parallel iterator.
+    ///
+    /// The values are inserted in the order of the iterator,
+    /// thus the keys they get are deterministic.
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = V>,
+    {
+        self.inner.par_extend(par_iter)
+    }
+}
+
+impl<K: SimpleKey, V: Send> FromParallelIterator<V> for SimpleSurotto<K, V> {
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = V>,
+    {
+        Self {
+            inner: Vec::from_par_iter(par_iter),
+            phantom: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{SimpleKey, SimpleSurotto};
+
+impl<K: SimpleKey, V: Serialize> Serialize for SimpleSurotto<K, V> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.inner.serialize(serializer)
+    }
+}
+
+impl<'de, K: SimpleKey, V: Deserialize<'de>> Deserialize<'de> for SimpleSurotto<K, V> {
+    /// Deserializes a surotto which was serialized before.
+    ///
+    /// Like [`SimpleSurotto::new`], this creates a surotto for the key type `K`,
+    /// so it must be the only one with that key type.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Self {
+            inner: Vec::deserialize(deserializer)?,
+            phantom: PhantomData,
+        })
+    }
+}
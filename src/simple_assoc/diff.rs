@@ -0,0 +1,42 @@
+use crate::simple::SimpleKey;
+
+use super::{join::OuterJoin, SimpleAssocSurotto};
+
+impl<K: SimpleKey, V: PartialEq> SimpleAssocSurotto<K, V> {
+    /// An iterator visiting all keys whose values differ between this surotto
+    /// and `newer`, e.g. to replicate only the changes of a snapshot.
+    /// The iterator element type is `Change<'a, K, V>`.
+    pub fn diff<'a>(&'a self, newer: &'a SimpleAssocSurotto<K, V>) -> Diff<'a, K, V> {
+        Diff {
+            inner: self.outer_join(newer),
+        }
+    }
+}
+
+/// A difference between two [`SimpleAssocSurotto`]s, see [`SimpleAssocSurotto::diff`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum Change<'a, K: SimpleKey, V> {
+    /// The key only has a value in the newer surotto.
+    Inserted(K, &'a V),
+    /// The key only has a value in the older surotto.
+    Removed(K, &'a V),
+    /// The key has different values, the older one first.
+    Modified(K, &'a V, &'a V),
+}
+
+pub struct Diff<'a, K: SimpleKey, V> {
+    inner: OuterJoin<'a, K, V, V>,
+}
+
+impl<'a, K: SimpleKey, V: PartialEq> Iterator for Diff<'a, K, V> {
+    type Item = Change<'a, K, V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.find_map(|(key, old, new)| match (old, new) {
+            (None, Some(new)) => Some(Change::Inserted(key, new)),
+            (Some(old), None) => Some(Change::Removed(key, old)),
+            (Some(old), Some(new)) if old != new => Some(Change::Modified(key, old, new)),
+            _ => None,
+        })
+    }
+}
@@ -1,6 +1,6 @@
-use std::{hint::unreachable_unchecked, iter};
+use std::iter;
 
-use crate::simple::SimpleKey;
+use crate::{simple::SimpleKey, unchecked};
 
 use super::SimpleAssocSurotto;
 
@@ -116,20 +116,15 @@ impl<'a, K: SimpleKey, V> OccupiedEntry<'a, K, V> {
 
     /// Sets the value of the entry, and returns the entry's old value.
     pub fn insert(&mut self, value: V) -> V {
-        let slot = unsafe { self.surotto.inner.get_unchecked_mut(self.key.idx()) };
-        match slot.replace(value) {
-            Some(val) => val,
-            None => unsafe { unreachable_unchecked() },
-        }
+        let slot = unsafe { unchecked::get_mut(&mut self.surotto.inner, self.key.idx()) };
+        unsafe { unchecked::unwrap(slot.replace(value)) }
     }
 
     /// Takes the value out of the entry, and returns it.
     pub fn remove(self) -> V {
-        let slot = unsafe { self.surotto.inner.get_unchecked_mut(self.key.idx()) };
-        match std::mem::replace(slot, None) {
-            Some(val) => val,
-            None => unsafe { unreachable_unchecked() },
-        }
+        self.surotto.len -= 1;
+        let slot = unsafe { unchecked::get_mut(&mut self.surotto.inner, self.key.idx()) };
+        unsafe { unchecked::unwrap(slot.take()) }
     }
 }
 
@@ -142,16 +137,21 @@ impl<'a, K: SimpleKey, V> VacantEntry<'a, K, V> {
     /// Sets the value of the entry with the `VacantEntry`'s key,
     /// and returns a mutable reference to it.
     pub fn insert(self, value: V) -> &'a mut V {
-        let missing_slots = self.key.idx() - self.surotto.inner.len();
+        let missing_slots = (self.key.idx() + 1).saturating_sub(self.surotto.inner.len());
         self.surotto
             .inner
             .extend(iter::repeat_with(|| None).take(missing_slots));
 
-        unsafe {
-            self.surotto
-                .inner
-                .get_unchecked_mut(self.key.idx())
-                .insert(value)
-        }
+        self.surotto.len += 1;
+        unsafe { unchecked::get_mut(&mut self.surotto.inner, self.key.idx()).insert(value) }
+    }
+
+    /// Sets the value of the entry with the `VacantEntry`'s key,
+    /// and returns an `OccupiedEntry` for further manipulation.
+    pub fn insert_entry(self, value: V) -> OccupiedEntry<'a, K, V> {
+        let key = self.key;
+        let surotto = self.surotto;
+        surotto.insert(key, value);
+        OccupiedEntry { surotto, key }
     }
 }
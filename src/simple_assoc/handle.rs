@@ -0,0 +1,51 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::simple::SimpleKey;
+
+use super::SimpleAssocSurotto;
+
+/// A borrow of a surotto together with a key which is known to be present.
+///
+/// This `struct` is constructed from the [`handle`] method on [`SimpleAssocSurotto`].
+///
+/// [`handle`]: SimpleAssocSurotto::handle
+pub struct Handle<'a, K: SimpleKey, V> {
+    pub(super) surotto: &'a mut SimpleAssocSurotto<K, V>,
+    pub(super) key: K,
+}
+
+impl<'a, K: SimpleKey, V> Handle<'a, K, V> {
+    /// Returns the key of this handle.
+    pub fn key(&self) -> K {
+        self.key
+    }
+
+    /// Converts the `Handle` into a mutable reference to the value
+    /// with a lifetime bound to the surotto itself.
+    pub fn into_mut(self) -> &'a mut V {
+        unsafe {
+            // SAFETY: handles are only created for present keys and hold the surotto borrowed.
+            self.surotto.get_unchecked_mut(self.key)
+        }
+    }
+}
+
+impl<'a, K: SimpleKey, V> Deref for Handle<'a, K, V> {
+    type Target = V;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe {
+            // SAFETY: handles are only created for present keys and hold the surotto borrowed.
+            self.surotto.get_unchecked(self.key)
+        }
+    }
+}
+
+impl<'a, K: SimpleKey, V> DerefMut for Handle<'a, K, V> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe {
+            // SAFETY: handles are only created for present keys and hold the surotto borrowed.
+            self.surotto.get_unchecked_mut(self.key)
+        }
+    }
+}
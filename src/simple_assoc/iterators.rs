@@ -1,9 +1,13 @@
-use std::{iter, marker::PhantomData};
+use std::{cell::Cell, iter, marker::PhantomData};
 
 use crate::simple::SimpleKey;
 
+use super::slot_entry::{OccupiedSlotEntry, SlotEntry, VacantSlotEntry};
+
 pub struct Iter<'a, K: SimpleKey, V> {
     pub(super) inner: iter::Enumerate<core::slice::Iter<'a, Option<V>>>,
+    /// The amount of values which are left.
+    pub(super) len: usize,
     pub(super) phantom: PhantomData<K>,
 }
 
@@ -11,7 +15,7 @@ impl<'a, K: SimpleKey, V> Iterator for Iter<'a, K, V> {
     type Item = (K, &'a V);
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.inner.find_map(|(i, opt)| {
+        let item = self.inner.find_map(|(i, opt)| {
             opt.as_ref().map(|val| {
                 (
                     unsafe {
@@ -22,12 +26,43 @@ impl<'a, K: SimpleKey, V> Iterator for Iter<'a, K, V> {
                     val,
                 )
             })
-        })
+        })?;
+        self.len -= 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, K: SimpleKey, V> DoubleEndedIterator for Iter<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.inner.by_ref().rev().find_map(|(i, opt)| {
+            opt.as_ref().map(|val| {
+                (
+                    unsafe {
+                        // SAFETY: The iterator only returns elements which are present and
+                        //          elements can't be removed from the `SimpleSurotto`, thus the creation of the key is safe here.
+                        K::new(i)
+                    },
+                    val,
+                )
+            })
+        })?;
+        self.len -= 1;
+        Some(item)
     }
 }
 
+impl<'a, K: SimpleKey, V> ExactSizeIterator for Iter<'a, K, V> {}
+
+impl<'a, K: SimpleKey, V> iter::FusedIterator for Iter<'a, K, V> {}
+
 pub struct IterMut<'a, K: SimpleKey, V> {
     pub(super) inner: iter::Enumerate<core::slice::IterMut<'a, Option<V>>>,
+    /// The amount of values which are left.
+    pub(super) len: usize,
     pub(super) phantom: PhantomData<K>,
 }
 
@@ -35,7 +70,7 @@ impl<'a, K: SimpleKey, V> Iterator for IterMut<'a, K, V> {
     type Item = (K, &'a mut V);
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.inner.find_map(|(i, opt)| {
+        let item = self.inner.find_map(|(i, opt)| {
             opt.as_mut().map(|val| {
                 (
                     unsafe {
@@ -46,12 +81,43 @@ impl<'a, K: SimpleKey, V> Iterator for IterMut<'a, K, V> {
                     val,
                 )
             })
-        })
+        })?;
+        self.len -= 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, K: SimpleKey, V> DoubleEndedIterator for IterMut<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.inner.by_ref().rev().find_map(|(i, opt)| {
+            opt.as_mut().map(|val| {
+                (
+                    unsafe {
+                        // SAFETY: The iterator only returns elements which are present and
+                        //          elements can't be removed from the `SimpleSurotto`, thus the creation of the key is safe here.
+                        K::new(i)
+                    },
+                    val,
+                )
+            })
+        })?;
+        self.len -= 1;
+        Some(item)
     }
 }
 
+impl<'a, K: SimpleKey, V> ExactSizeIterator for IterMut<'a, K, V> {}
+
+impl<'a, K: SimpleKey, V> iter::FusedIterator for IterMut<'a, K, V> {}
+
 pub struct IntoIter<K: SimpleKey, V> {
     pub(super) inner: iter::Enumerate<std::vec::IntoIter<Option<V>>>,
+    /// The amount of values which are left.
+    pub(super) len: usize,
     pub(super) phantom: PhantomData<K>,
 }
 
@@ -59,7 +125,7 @@ impl<K: SimpleKey, V> Iterator for IntoIter<K, V> {
     type Item = (K, V);
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.inner.find_map(|(i, opt)| {
+        let item = self.inner.find_map(|(i, opt)| {
             opt.map(|val| {
                 (
                     unsafe {
@@ -70,10 +136,39 @@ impl<K: SimpleKey, V> Iterator for IntoIter<K, V> {
                     val,
                 )
             })
-        })
+        })?;
+        self.len -= 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
     }
 }
 
+impl<K: SimpleKey, V> DoubleEndedIterator for IntoIter<K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.inner.by_ref().rev().find_map(|(i, opt)| {
+            opt.map(|val| {
+                (
+                    unsafe {
+                        // SAFETY: The iterator only returns elements which are present and
+                        //          elements can't be removed from the `SimpleSurotto`, thus the creation of the key is safe here.
+                        K::new(i)
+                    },
+                    val,
+                )
+            })
+        })?;
+        self.len -= 1;
+        Some(item)
+    }
+}
+
+impl<K: SimpleKey, V> ExactSizeIterator for IntoIter<K, V> {}
+
+impl<K: SimpleKey, V> iter::FusedIterator for IntoIter<K, V> {}
+
 pub struct Keys<'a, K: SimpleKey, V> {
     pub(super) inner: Iter<'a, K, V>,
 }
@@ -84,8 +179,22 @@ impl<'a, K: SimpleKey, V> Iterator for Keys<'a, K, V> {
     fn next(&mut self) -> Option<Self::Item> {
         self.inner.next().map(|(key, _)| key)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, K: SimpleKey, V> DoubleEndedIterator for Keys<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(key, _)| key)
+    }
 }
 
+impl<'a, K: SimpleKey, V> ExactSizeIterator for Keys<'a, K, V> {}
+
+impl<'a, K: SimpleKey, V> iter::FusedIterator for Keys<'a, K, V> {}
+
 pub struct Values<'a, K: SimpleKey, V> {
     pub(super) inner: Iter<'a, K, V>,
 }
@@ -96,8 +205,22 @@ impl<'a, K: SimpleKey, V> Iterator for Values<'a, K, V> {
     fn next(&mut self) -> Option<Self::Item> {
         self.inner.next().map(|(_, val)| val)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
 }
 
+impl<'a, K: SimpleKey, V> DoubleEndedIterator for Values<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(_, val)| val)
+    }
+}
+
+impl<'a, K: SimpleKey, V> ExactSizeIterator for Values<'a, K, V> {}
+
+impl<'a, K: SimpleKey, V> iter::FusedIterator for Values<'a, K, V> {}
+
 pub struct ValuesMut<'a, K: SimpleKey, V> {
     pub(super) inner: IterMut<'a, K, V>,
 }
@@ -108,4 +231,138 @@ impl<'a, K: SimpleKey, V> Iterator for ValuesMut<'a, K, V> {
     fn next(&mut self) -> Option<Self::Item> {
         self.inner.next().map(|(_, val)| val)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, K: SimpleKey, V> DoubleEndedIterator for ValuesMut<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(_, val)| val)
+    }
+}
+
+impl<'a, K: SimpleKey, V> ExactSizeIterator for ValuesMut<'a, K, V> {}
+
+impl<'a, K: SimpleKey, V> iter::FusedIterator for ValuesMut<'a, K, V> {}
+
+pub struct IntoKeys<K: SimpleKey, V> {
+    pub(super) inner: IntoIter<K, V>,
+}
+
+impl<K: SimpleKey, V> Iterator for IntoKeys<K, V> {
+    type Item = K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(key, _)| key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K: SimpleKey, V> DoubleEndedIterator for IntoKeys<K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(key, _)| key)
+    }
+}
+
+impl<K: SimpleKey, V> ExactSizeIterator for IntoKeys<K, V> {}
+
+impl<K: SimpleKey, V> iter::FusedIterator for IntoKeys<K, V> {}
+
+pub struct IntoValues<K: SimpleKey, V> {
+    pub(super) inner: IntoIter<K, V>,
+}
+
+impl<K: SimpleKey, V> Iterator for IntoValues<K, V> {
+    type Item = V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, val)| val)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K: SimpleKey, V> DoubleEndedIterator for IntoValues<K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(_, val)| val)
+    }
+}
+
+impl<K: SimpleKey, V> ExactSizeIterator for IntoValues<K, V> {}
+
+impl<K: SimpleKey, V> iter::FusedIterator for IntoValues<K, V> {}
+
+pub struct IterEntries<'a, K: SimpleKey, V> {
+    pub(super) inner: iter::Enumerate<core::slice::IterMut<'a, Option<V>>>,
+    pub(super) len: &'a Cell<usize>,
+    pub(super) phantom: PhantomData<K>,
+}
+
+impl<'a, K: SimpleKey, V> Iterator for IterEntries<'a, K, V> {
+    type Item = SlotEntry<'a, K, V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(i, slot)| {
+            let key = unsafe {
+                // SAFETY: The iterator only visits the slots up to the length of the primary
+                //          `SimpleSurotto`, whose keys are dense, thus every slot index is a valid key.
+                K::new(i)
+            };
+            let len = self.len;
+            if slot.is_some() {
+                SlotEntry::Occupied(OccupiedSlotEntry { slot, len, key })
+            } else {
+                SlotEntry::Vacant(VacantSlotEntry { slot, len, key })
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, K: SimpleKey, V> ExactSizeIterator for IterEntries<'a, K, V> {}
+
+impl<'a, K: SimpleKey, V> iter::FusedIterator for IterEntries<'a, K, V> {}
+
+pub struct ExtractIf<'a, K: SimpleKey, V, F>
+where
+    F: FnMut(K, &mut V) -> bool,
+{
+    pub(super) inner: iter::Enumerate<core::slice::IterMut<'a, Option<V>>>,
+    pub(super) len: &'a mut usize,
+    pub(super) pred: F,
+    pub(super) phantom: PhantomData<K>,
+}
+
+impl<'a, K: SimpleKey, V, F> Iterator for ExtractIf<'a, K, V, F>
+where
+    F: FnMut(K, &mut V) -> bool,
+{
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (i, slot) in self.inner.by_ref() {
+            if let Some(val) = slot {
+                let key = unsafe {
+                    // SAFETY: The iterator only returns elements which are present and
+                    //          elements can't be removed from the `SimpleSurotto`, thus the creation of the key is safe here.
+                    K::new(i)
+                };
+                if (self.pred)(key, val) {
+                    *self.len -= 1;
+                    return slot.take().map(|val| (key, val));
+                }
+            }
+        }
+        None
+    }
 }
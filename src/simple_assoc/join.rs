@@ -0,0 +1,171 @@
+use std::{iter, marker::PhantomData, slice};
+
+use crate::{
+    join::{JoinPrimary, JoinSource},
+    simple::SimpleKey,
+};
+
+use super::SimpleAssocSurotto;
+
+impl<K: SimpleKey, V> SimpleAssocSurotto<K, V> {
+    /// An iterator visiting all keys of this surotto together with the value
+    /// of the other surotto, if it has one.
+    /// The iterator element type is `(K, &'a V, Option<&'a W>)`.
+    pub fn left_join<'a, W>(
+        &'a self,
+        other: &'a SimpleAssocSurotto<K, W>,
+    ) -> LeftJoin<'a, K, V, W> {
+        LeftJoin {
+            left: &self.inner,
+            right: &other.inner,
+            idx: 0,
+            phantom: PhantomData,
+        }
+    }
+
+    /// An iterator visiting all keys present in either surotto,
+    /// i.e. the union of both.
+    /// The iterator element type is `(K, Option<&'a V>, Option<&'a W>)`,
+    /// where at least one of the values is present.
+    pub fn outer_join<'a, W>(
+        &'a self,
+        other: &'a SimpleAssocSurotto<K, W>,
+    ) -> OuterJoin<'a, K, V, W> {
+        OuterJoin {
+            left: &self.inner,
+            right: &other.inner,
+            idx: 0,
+            phantom: PhantomData,
+        }
+    }
+
+    /// An iterator visiting all keys present in both surottos,
+    /// i.e. the intersection of both.
+    /// The iterator element type is `(K, &'a V, &'a W)`.
+    pub fn inner_join<'a, W>(
+        &'a self,
+        other: &'a SimpleAssocSurotto<K, W>,
+    ) -> InnerJoin<'a, K, V, W> {
+        InnerJoin {
+            left: &self.inner,
+            right: &other.inner,
+            idx: 0,
+            phantom: PhantomData,
+        }
+    }
+}
+
+pub struct LeftJoin<'a, K: SimpleKey, V, W> {
+    left: &'a [Option<V>],
+    right: &'a [Option<W>],
+    idx: usize,
+    phantom: PhantomData<K>,
+}
+
+impl<'a, K: SimpleKey, V, W> Iterator for LeftJoin<'a, K, V, W> {
+    type Item = (K, &'a V, Option<&'a W>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(left) = self.left.get(self.idx) {
+            let i = self.idx;
+            self.idx += 1;
+            if let Some(left) = left {
+                let right = self.right.get(i).and_then(Option::as_ref);
+                return Some((
+                    unsafe {
+                        // SAFETY: The slot is occupied, so the key was created by the `SimpleSurotto` before.
+                        K::new(i)
+                    },
+                    left,
+                    right,
+                ));
+            }
+        }
+        None
+    }
+}
+
+pub struct OuterJoin<'a, K: SimpleKey, V, W> {
+    left: &'a [Option<V>],
+    right: &'a [Option<W>],
+    idx: usize,
+    phantom: PhantomData<K>,
+}
+
+impl<'a, K: SimpleKey, V, W> Iterator for OuterJoin<'a, K, V, W> {
+    type Item = (K, Option<&'a V>, Option<&'a W>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.idx < self.left.len().max(self.right.len()) {
+            let i = self.idx;
+            self.idx += 1;
+            let left = self.left.get(i).and_then(Option::as_ref);
+            let right = self.right.get(i).and_then(Option::as_ref);
+            if left.is_some() || right.is_some() {
+                return Some((
+                    unsafe {
+                        // SAFETY: One of the slots is occupied, so the key was created by the `SimpleSurotto` before.
+                        K::new(i)
+                    },
+                    left,
+                    right,
+                ));
+            }
+        }
+        None
+    }
+}
+
+pub struct InnerJoin<'a, K: SimpleKey, V, W> {
+    left: &'a [Option<V>],
+    right: &'a [Option<W>],
+    idx: usize,
+    phantom: PhantomData<K>,
+}
+
+impl<'a, K: SimpleKey, V, W> Iterator for InnerJoin<'a, K, V, W> {
+    type Item = (K, &'a V, &'a W);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let (Some(left), Some(right)) = (self.left.get(self.idx), self.right.get(self.idx)) {
+            let i = self.idx;
+            self.idx += 1;
+            if let (Some(left), Some(right)) = (left, right) {
+                return Some((
+                    unsafe {
+                        // SAFETY: Both slots are occupied, so the key was created by the `SimpleSurotto` before.
+                        K::new(i)
+                    },
+                    left,
+                    right,
+                ));
+            }
+        }
+        None
+    }
+}
+
+impl<'a, K: SimpleKey, V> JoinSource<K> for &'a SimpleAssocSurotto<K, V> {
+    type Item = &'a V;
+    type Slots = iter::Map<slice::Iter<'a, Option<V>>, fn(&'a Option<V>) -> Option<&'a V>>;
+
+    fn slots(self) -> Self::Slots {
+        self.inner.iter().map(Option::as_ref)
+    }
+}
+
+// SAFETY: Slots are only occupied for keys which were created by the `SimpleSurotto` before.
+unsafe impl<K: SimpleKey, V> JoinPrimary<K> for &SimpleAssocSurotto<K, V> {}
+
+impl<'a, K: SimpleKey, V> JoinSource<K> for &'a mut SimpleAssocSurotto<K, V> {
+    type Item = &'a mut V;
+    type Slots =
+        iter::Map<slice::IterMut<'a, Option<V>>, fn(&'a mut Option<V>) -> Option<&'a mut V>>;
+
+    fn slots(self) -> Self::Slots {
+        self.inner.iter_mut().map(Option::as_mut)
+    }
+}
+
+// SAFETY: Slots are only occupied for keys which were created by the `SimpleSurotto` before.
+unsafe impl<K: SimpleKey, V> JoinPrimary<K> for &mut SimpleAssocSurotto<K, V> {}
@@ -1,20 +1,55 @@
-use std::{collections::TryReserveError, iter, marker::PhantomData};
+use std::{
+    cell::Cell,
+    collections::TryReserveError,
+    fmt, iter,
+    marker::PhantomData,
+    ops::{Bound, Range, RangeBounds},
+};
 
-use crate::simple::SimpleKey;
+use crate::{
+    simple::{has_duplicates, SimpleKey, SimpleSurotto},
+    unchecked,
+};
 
 use self::{
     entry::{Entry, OccupiedEntry, VacantEntry},
-    iterators::{IntoIter, Iter, IterMut, Keys, Values, ValuesMut},
+    handle::Handle,
+    iterators::{
+        ExtractIf, IntoIter, IntoKeys, IntoValues, Iter, IterEntries, IterMut, Keys, Values,
+        ValuesMut,
+    },
 };
 
+pub mod diff;
 pub mod entry;
+pub mod handle;
 pub mod iterators;
+pub mod join;
+pub mod slot_entry;
+pub mod stats;
+
+#[cfg(feature = "rayon")]
+pub mod rayon;
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
+#[cfg(feature = "rand")]
+mod rand;
 
 /// A datastructure where values can be associated with a key from a [`SimpleSurotto`].
 ///
+/// # Important
+///
+/// With the `serde` feature, slots are serialized in order so keys survive a round trip.
+/// Deserializing needs the primary [`SimpleSurotto`], see `serde::AssocSeed`.
+///
 /// [`SimpleSurotto`]: crate::simple::SimpleSurotto
+#[derive(Clone)]
 pub struct SimpleAssocSurotto<K: SimpleKey, V> {
     inner: Vec<Option<V>>,
+    /// The amount of occupied slots.
+    len: usize,
     phantom: PhantomData<K>,
 }
 
@@ -25,6 +60,7 @@ impl<K: SimpleKey, V> SimpleAssocSurotto<K, V> {
     pub const fn new() -> Self {
         Self {
             inner: Vec::new(),
+            len: 0,
             phantom: PhantomData,
         }
     }
@@ -41,6 +77,7 @@ impl<K: SimpleKey, V> SimpleAssocSurotto<K, V> {
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
             inner: Vec::with_capacity(capacity),
+            len: 0,
             phantom: PhantomData,
         }
     }
@@ -54,24 +91,45 @@ impl<K: SimpleKey, V> SimpleAssocSurotto<K, V> {
     pub fn insert(&mut self, key: K, value: V) -> Option<V> {
         let key = key.idx();
 
-        let missing_slots = key - self.inner.len();
+        let missing_slots = (key + 1).saturating_sub(self.inner.len());
         self.inner
             .extend(iter::repeat_with(|| None).take(missing_slots));
 
-        unsafe {
+        let old = unsafe {
             // SAFETY: we just enlarged the bounds to make the slot at key in length.
-            self.inner.get_unchecked_mut(key).replace(value)
+            unchecked::get_mut(&mut self.inner, key).replace(value)
+        };
+        if old.is_none() {
+            self.len += 1;
         }
+        old
+    }
+
+    /// Swaps the slots of two keys, so their values trade places.
+    ///
+    /// If only one key has a value, the value is moved to the other key.
+    pub fn swap(&mut self, a: K, b: K) {
+        let (low, high) = (a.idx().min(b.idx()), a.idx().max(b.idx()));
+        if high >= self.inner.len() {
+            if !matches!(self.inner.get(low), Some(Some(_))) {
+                // Both slots are vacant, there's nothing to swap.
+                return;
+            }
+            let missing_slots = high + 1 - self.inner.len();
+            self.inner
+                .extend(iter::repeat_with(|| None).take(missing_slots));
+        }
+        self.inner.swap(low, high);
     }
 
     /// Removes a value from the surotto, returning the value at the
     /// key if the key was previously in the surotto.
     pub fn remove(&mut self, key: K) -> Option<V> {
-        if let Some(slot) = self.inner.get_mut(key.idx()) {
-            slot.take()
-        } else {
-            None
+        let old = self.inner.get_mut(key.idx())?.take();
+        if old.is_some() {
+            self.len -= 1;
         }
+        old
     }
 
     /// Returns a reference to the value corresponding to the key.
@@ -90,20 +148,90 @@ impl<K: SimpleKey, V> SimpleAssocSurotto<K, V> {
         }
     }
 
+    /// Returns the key-value pair corresponding to the supplied key.
+    ///
+    /// The returned key is the canonical key of the slot, created by the surotto itself.
+    pub fn get_key_value(&self, key: K) -> Option<(K, &V)> {
+        let idx = key.idx();
+        match self.inner.get(idx) {
+            Some(Some(val)) => Some((
+                unsafe {
+                    // SAFETY: The slot is occupied, so the key was created by the `SimpleSurotto` before.
+                    K::new(idx)
+                },
+                val,
+            )),
+            _ => None,
+        }
+    }
+
+    /// Returns the key and value at the index, or `None` if the slot is vacant.
+    pub fn get_by_index(&self, idx: usize) -> Option<(K, &V)> {
+        self.inner.get(idx)?.as_ref().map(|val| {
+            (
+                unsafe {
+                    // SAFETY: The slot is occupied, so the key was created by the `SimpleSurotto` before.
+                    K::new(idx)
+                },
+                val,
+            )
+        })
+    }
+
+    /// Returns the key and a mutable reference to the value at the index,
+    /// or `None` if the slot is vacant.
+    pub fn get_by_index_mut(&mut self, idx: usize) -> Option<(K, &mut V)> {
+        self.inner.get_mut(idx)?.as_mut().map(|val| {
+            (
+                unsafe {
+                    // SAFETY: The slot is occupied, so the key was created by the `SimpleSurotto` before.
+                    K::new(idx)
+                },
+                val,
+            )
+        })
+    }
+
+    /// Returns mutable references to the values of all given keys at once.
+    ///
+    /// Returns `None` if any key is missing or occurs more than once.
+    pub fn get_disjoint_mut_slice(&mut self, keys: &[K]) -> Option<Vec<&mut V>> {
+        if has_duplicates(keys) || !keys.iter().all(|&key| self.contains_key(key)) {
+            return None;
+        }
+
+        let slots = unsafe {
+            // SAFETY: We checked that the keys are present and distinct,
+            //          thus the references are valid and don't alias.
+            unchecked::get_disjoint_mut(&mut self.inner, keys.iter().map(|key| key.idx()))
+        };
+        Some(
+            slots
+                .into_iter()
+                .map(|slot| unsafe {
+                    // SAFETY: We checked that the keys are present.
+                    unchecked::unwrap(slot.as_mut())
+                })
+                .collect(),
+        )
+    }
+
     /// Returns a reference to an element without checking on the key or bounds
+    ///
+    /// # Safety
+    ///
+    /// The surotto must contain a value for the key.
     pub unsafe fn get_unchecked(&self, key: K) -> &V {
-        self.inner
-            .get_unchecked(key.idx())
-            .as_ref()
-            .unwrap_unchecked()
+        unchecked::unwrap(unchecked::get(&self.inner, key.idx()).as_ref())
     }
 
     /// Returns a mutable reference to an element without checking on the key or bounds
+    ///
+    /// # Safety
+    ///
+    /// The surotto must contain a value for the key.
     pub unsafe fn get_unchecked_mut(&mut self, key: K) -> &mut V {
-        self.inner
-            .get_unchecked_mut(key.idx())
-            .as_mut()
-            .unwrap_unchecked()
+        unchecked::unwrap(unchecked::get_mut(&mut self.inner, key.idx()).as_mut())
     }
 
     /// Gets the given key's corresponding entry in the surotto for in-place manipulation.
@@ -115,6 +243,16 @@ impl<K: SimpleKey, V> SimpleAssocSurotto<K, V> {
         }
     }
 
+    /// Returns a handle bundling the borrowed surotto with the key,
+    /// if the key is present.
+    pub fn handle(&mut self, key: K) -> Option<Handle<'_, K, V>> {
+        if self.contains_key(key) {
+            Some(Handle { surotto: self, key })
+        } else {
+            None
+        }
+    }
+
     /// Returns true if the map contains a value for the specified key.
     pub fn contains_key(&self, key: K) -> bool {
         self.inner
@@ -123,15 +261,25 @@ impl<K: SimpleKey, V> SimpleAssocSurotto<K, V> {
             .unwrap_or(false)
     }
 
+    /// Clears the surotto, removing all values.
+    ///
+    /// Note that this method has no effect on the allocated capacity of the surotto.
+    pub fn clear(&mut self) {
+        self.inner.clear();
+        self.len = 0;
+    }
+
     /// Returns true if the surotto contains no elements.
     pub fn is_empty(&self) -> bool {
-        self.inner.is_empty()
+        self.len == 0
     }
 
     /// Returns the number of elements in the surotto, also referred to
     /// as its 'length'.
+    ///
+    /// Only occupied slots are counted, so trimming vacant slots doesn't change it.
     pub fn len(&self) -> usize {
-        self.inner.len()
+        self.len
     }
 
     /// Returns the total number of elements the surotto can hold without
@@ -211,37 +359,203 @@ impl<K: SimpleKey, V> SimpleAssocSurotto<K, V> {
 
     /// Shrinks the capacity of the surotto as much as possible.
     ///
-    /// It will drop down as close as possible to the length but the allocator
+    /// Vacant slots after the last occupied one are trimmed first.
+    /// It will drop down as close as possible to the remaining slots but the allocator
     /// may still inform the vector that there is space for a few more elements.
     pub fn shrink_to_fit(&mut self) {
+        self.trim_vacant();
         self.inner.shrink_to_fit()
     }
 
     /// Shrinks the capacity of the surotto with a lower bound.
     ///
-    /// The capacity will remain at least as large as both the length
+    /// Vacant slots after the last occupied one are trimmed first.
+    /// The capacity will remain at least as large as both the remaining slots
     /// and the supplied value.
     ///
     /// If the current capacity is less than the lower limit, this is a no-op.
     pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.trim_vacant();
         self.inner.shrink_to(min_capacity)
     }
 
+    fn trim_vacant(&mut self) {
+        let len = self
+            .inner
+            .iter()
+            .rposition(Option::is_some)
+            .map_or(0, |i| i + 1);
+        self.inner.truncate(len);
+    }
+
+    /// Clones only the entries of the given keys into a new surotto.
+    ///
+    /// The entries keep their keys, so the keys work against the clone too.
+    /// Keys which aren't present in the surotto are skipped.
+    pub fn clone_filtered<I>(&self, keys: I) -> Self
+    where
+        I: IntoIterator<Item = K>,
+        V: Clone,
+    {
+        let mut surotto = Self::new();
+        for key in keys {
+            if let Some(val) = self.get(key) {
+                surotto.insert(key, val.clone());
+            }
+        }
+        surotto
+    }
+
+    /// Creates an iterator which uses a closure to determine if an element should be removed.
+    ///
+    /// If the closure returns true, the element is removed from the surotto and yielded.
+    /// If the closure returns false, the element remains in the surotto.
+    ///
+    /// The iterator is lazy: elements it didn't visit before being dropped stay in the surotto.
+    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, K, V, F>
+    where
+        F: FnMut(K, &mut V) -> bool,
+    {
+        ExtractIf {
+            inner: self.inner.iter_mut().enumerate(),
+            len: &mut self.len,
+            pred,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Moves all elements for which the predicate returns `true` into a new surotto.
+    ///
+    /// The elements keep their keys, so both surottos stay associated with the
+    /// same [`SimpleSurotto`].
+    pub fn split_off_where<F>(&mut self, pred: F) -> Self
+    where
+        F: FnMut(K, &mut V) -> bool,
+    {
+        let mut other = Self::new();
+        for (key, value) in self.extract_if(pred) {
+            other.insert(key, value);
+        }
+        other
+    }
+
+    /// Returns true if both surottos contain equal values in the same order,
+    /// regardless of the keys they are stored under.
+    pub fn values_eq(&self, other: &Self) -> bool
+    where
+        V: PartialEq,
+    {
+        self.values().eq(other.values())
+    }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// In other words, remove all pairs `(k, v)` for which `f(k, &mut v)` returns `false`.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(K, &mut V) -> bool,
+    {
+        for (i, slot) in self.inner.iter_mut().enumerate() {
+            if let Some(val) = slot {
+                let key = unsafe {
+                    // SAFETY: the slot is occupied, so the key was created by the `SimpleSurotto` before.
+                    K::new(i)
+                };
+                if !f(key, val) {
+                    *slot = None;
+                    self.len -= 1;
+                }
+            }
+        }
+    }
+
     /// An iterator visiting all key-value pairs.
     /// The iterator element type is `(K, &'a V)`.
     pub fn iter(&self) -> Iter<'_, K, V> {
         Iter {
             inner: self.inner.iter().enumerate(),
+            len: self.len,
             phantom: PhantomData,
         }
     }
 
+    /// An iterator visiting all key-value pairs whose key index lies in the range.
+    /// The iterator element type is `(K, &'a V)`.
+    ///
+    /// Unless the range covers all slots, the values in it are counted up front,
+    /// which takes time linear in the length of the range.
+    pub fn iter_index_range(&self, range: Range<usize>) -> Iter<'_, K, V> {
+        let end = range.end.min(self.inner.len());
+        let start = range.start.min(end);
+        let len = if start == 0 && end == self.inner.len() {
+            self.len
+        } else {
+            self.inner[start..end]
+                .iter()
+                .filter(|slot| slot.is_some())
+                .count()
+        };
+        let mut inner = self.inner[..end].iter().enumerate();
+        if start > 0 {
+            inner.nth(start - 1);
+        }
+        Iter {
+            inner,
+            len,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the key with the lowest index which has a value.
+    pub fn first_key(&self) -> Option<K> {
+        self.keys().next()
+    }
+
+    /// Returns the key with the highest index which has a value.
+    pub fn last_key(&self) -> Option<K> {
+        self.keys().next_back()
+    }
+
+    /// Returns the next key after the given one in index order which has a value,
+    /// e.g. to resume a paginated traversal.
+    pub fn next_key_after(&self, key: K) -> Option<K> {
+        let start = key.idx() + 1;
+        let offset = self.inner.get(start..)?.iter().position(Option::is_some)?;
+        Some(unsafe {
+            // SAFETY: The slot is occupied, so the key was created by the `SimpleSurotto` before.
+            K::new(start + offset)
+        })
+    }
+
+    /// An iterator visiting all keys in the range which have a value, in index order.
+    /// The iterator element type is `K`.
+    ///
+    /// Like with [`iter_index_range`], the keys in the range are counted up front.
+    ///
+    /// [`iter_index_range`]: Self::iter_index_range
+    pub fn keys_range<R: RangeBounds<K>>(&self, range: R) -> Keys<'_, K, V> {
+        let start = match range.start_bound() {
+            Bound::Included(key) => key.idx(),
+            Bound::Excluded(key) => key.idx() + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(key) => key.idx() + 1,
+            Bound::Excluded(key) => key.idx(),
+            Bound::Unbounded => usize::MAX,
+        };
+        Keys {
+            inner: self.iter_index_range(start..end),
+        }
+    }
+
     /// An iterator visiting all key-value pairs,
     /// with mutable references to the values.
     /// The iterator element type is `(K, &'a mut V)`.
     pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
         IterMut {
             inner: self.inner.iter_mut().enumerate(),
+            len: self.len,
             phantom: PhantomData,
         }
     }
@@ -265,8 +579,90 @@ impl<K: SimpleKey, V> SimpleAssocSurotto<K, V> {
             inner: self.iter_mut(),
         }
     }
+
+    /// Creates a consuming iterator visiting all keys.
+    /// The surotto cannot be used after calling this.
+    /// The iterator element type is `K`.
+    pub fn into_keys(self) -> IntoKeys<K, V> {
+        IntoKeys {
+            inner: self.into_iter(),
+        }
+    }
+
+    /// Creates a consuming iterator visiting all values.
+    /// The surotto cannot be used after calling this.
+    /// The iterator element type is `V`.
+    pub fn into_values(self) -> IntoValues<K, V> {
+        IntoValues {
+            inner: self.into_iter(),
+        }
+    }
+
+    /// An iterator visiting the entries of all keys of the primary surotto,
+    /// occupied or vacant, for in-place manipulation.
+    /// The iterator element type is `SlotEntry<'a, K, V>`.
+    ///
+    /// The keys of vacant slots are vouched for by the primary surotto, which hands out
+    /// keys without gaps. Thus there is no variant without a primary surotto.
+    ///
+    /// The surotto first grows vacant slots up to the length of the primary surotto.
+    /// They don't change [`len`], but keep their memory until [`shrink_to_fit`] is called.
+    ///
+    /// [`len`]: Self::len
+    /// [`shrink_to_fit`]: Self::shrink_to_fit
+    pub fn iter_entries_for<P>(&mut self, primary: &SimpleSurotto<K, P>) -> IterEntries<'_, K, V> {
+        let missing_slots = primary.len().saturating_sub(self.inner.len());
+        self.inner
+            .extend(iter::repeat_with(|| None).take(missing_slots));
+
+        IterEntries {
+            inner: self.inner[..primary.len()].iter_mut().enumerate(),
+            len: Cell::from_mut(&mut self.len),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Calls a closure on every key-value pair, with mutable references to the values.
+    ///
+    /// Unlike going through [`iter_mut`], this is a plain internal loop,
+    /// which usually optimizes better for small closures.
+    ///
+    /// [`iter_mut`]: Self::iter_mut
+    pub fn for_each_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(K, &mut V),
+    {
+        for (i, slot) in self.inner.iter_mut().enumerate() {
+            if let Some(val) = slot {
+                f(
+                    unsafe {
+                        // SAFETY: the slot is occupied, so the key was created by the `SimpleSurotto` before.
+                        K::new(i)
+                    },
+                    val,
+                );
+            }
+        }
+    }
 }
 
+impl<K: SimpleKey + fmt::Debug, V: fmt::Debug> fmt::Debug for SimpleAssocSurotto<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: SimpleKey, V: PartialEq> PartialEq for SimpleAssocSurotto<K, V> {
+    /// Compares the occupied slots by their index and value.
+    fn eq(&self, other: &Self) -> bool {
+        self.iter()
+            .map(|(key, val)| (key.idx(), val))
+            .eq(other.iter().map(|(key, val)| (key.idx(), val)))
+    }
+}
+
+impl<K: SimpleKey, V: Eq> Eq for SimpleAssocSurotto<K, V> {}
+
 impl<K: SimpleKey, V> Default for SimpleAssocSurotto<K, V> {
     fn default() -> Self {
         Self::new()
@@ -280,6 +676,7 @@ impl<K: SimpleKey, V> IntoIterator for SimpleAssocSurotto<K, V> {
     fn into_iter(self) -> Self::IntoIter {
         IntoIter {
             inner: self.inner.into_iter().enumerate(),
+            len: self.len,
             phantom: PhantomData,
         }
     }
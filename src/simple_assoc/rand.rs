@@ -0,0 +1,38 @@
+use rand::{seq::IteratorRandom, Rng};
+
+use crate::simple::SimpleKey;
+
+use super::SimpleAssocSurotto;
+
+/// How many random slots are tried before falling back to a full scan.
+const RANDOM_ATTEMPTS: usize = 16;
+
+impl<K: SimpleKey, V> SimpleAssocSurotto<K, V> {
+    /// Returns the key of a uniformly random element, or `None` if the surotto is empty.
+    ///
+    /// Random slots are tried first, which takes expected O(1) unless most slots are vacant.
+    /// After a few vacant hits, all elements are scanned instead.
+    pub fn random_key<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<K> {
+        if self.inner.is_empty() {
+            return None;
+        }
+        for _ in 0..RANDOM_ATTEMPTS {
+            let idx = rng.gen_range(0..self.inner.len());
+            if self.inner[idx].is_some() {
+                return Some(unsafe {
+                    // SAFETY: the slot is occupied, so the key was created by the `SimpleSurotto` before.
+                    K::new(idx)
+                });
+            }
+        }
+        self.keys().choose(rng)
+    }
+
+    /// Returns the keys of `amount` distinct, uniformly random elements,
+    /// or all keys if the surotto contains fewer.
+    ///
+    /// This visits every slot.
+    pub fn sample_keys<R: Rng + ?Sized>(&self, rng: &mut R, amount: usize) -> Vec<K> {
+        self.keys().choose_multiple(rng, amount)
+    }
+}
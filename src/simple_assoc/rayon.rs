@@ -0,0 +1,245 @@
+use std::marker::PhantomData;
+
+use rayon::{
+    iter::{
+        plumbing::UnindexedConsumer, FromParallelIterator, IndexedParallelIterator,
+        IntoParallelIterator, IntoParallelRefIterator, IntoParallelRefMutIterator, ParallelExtend,
+        ParallelIterator,
+    },
+    slice, vec,
+};
+
+use crate::simple::SimpleKey;
+
+use super::SimpleAssocSurotto;
+
+impl<K: SimpleKey, V> SimpleAssocSurotto<K, V> {
+    /// A parallel iterator visiting all key-value pairs.
+    /// The iterator element type is `(K, &'a V)`.
+    pub fn par_iter(&self) -> ParIter<'_, K, V>
+    where
+        V: Sync,
+    {
+        ParIter {
+            inner: self.inner.par_iter(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// A parallel iterator visiting all key-value pairs,
+    /// with mutable references to the values.
+    /// The iterator element type is `(K, &'a mut V)`.
+    pub fn par_iter_mut(&mut self) -> ParIterMut<'_, K, V>
+    where
+        V: Send,
+    {
+        ParIterMut {
+            inner: self.inner.par_iter_mut(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Retains only the elements specified by the predicate,
+    /// evaluating it for all elements in parallel.
+    ///
+    /// In other words, remove all pairs `(k, v)` for which `f(k, &mut v)` returns `false`.
+    pub fn par_retain<F>(&mut self, f: F)
+    where
+        F: Fn(K, &mut V) -> bool + Sync + Send,
+        V: Send,
+    {
+        let removed = self
+            .inner
+            .par_iter_mut()
+            .enumerate()
+            .map(|(i, slot)| {
+                if let Some(val) = slot {
+                    let key = unsafe {
+                        // SAFETY: the slot is occupied, so the key was created by the `SimpleSurotto` before.
+                        K::new(i)
+                    };
+                    if !f(key, val) {
+                        *slot = None;
+                        return true;
+                    }
+                }
+                false
+            })
+            .filter(|&removed| removed)
+            .count();
+        self.len -= removed;
+    }
+
+    /// A parallel iterator visiting all values mutably.
+    /// The iterator element type is `&'a mut V`.
+    pub fn par_values_mut(&mut self) -> ParValuesMut<'_, K, V>
+    where
+        V: Send,
+    {
+        ParValuesMut {
+            inner: self.par_iter_mut(),
+        }
+    }
+}
+
+pub struct ParIter<'a, K: SimpleKey, V: Sync> {
+    inner: slice::Iter<'a, Option<V>>,
+    phantom: PhantomData<K>,
+}
+
+impl<'a, K: SimpleKey + Send, V: Sync> ParallelIterator for ParIter<'a, K, V> {
+    type Item = (K, &'a V);
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        self.inner
+            .enumerate()
+            .filter_map(|(i, opt)| {
+                opt.as_ref().map(|val| {
+                    (
+                        unsafe {
+                            // SAFETY: The iterator only returns elements which are present and
+                            //          elements can't be removed from the `SimpleSurotto`, thus the creation of the key is safe here.
+                            K::new(i)
+                        },
+                        val,
+                    )
+                })
+            })
+            .drive_unindexed(consumer)
+    }
+}
+
+pub struct ParIterMut<'a, K: SimpleKey, V: Send> {
+    inner: slice::IterMut<'a, Option<V>>,
+    phantom: PhantomData<K>,
+}
+
+impl<'a, K: SimpleKey + Send, V: Send> ParallelIterator for ParIterMut<'a, K, V> {
+    type Item = (K, &'a mut V);
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        self.inner
+            .enumerate()
+            .filter_map(|(i, opt)| {
+                opt.as_mut().map(|val| {
+                    (
+                        unsafe {
+                            // SAFETY: The iterator only returns elements which are present and
+                            //          elements can't be removed from the `SimpleSurotto`, thus the creation of the key is safe here.
+                            K::new(i)
+                        },
+                        val,
+                    )
+                })
+            })
+            .drive_unindexed(consumer)
+    }
+}
+
+pub struct ParValuesMut<'a, K: SimpleKey, V: Send> {
+    inner: ParIterMut<'a, K, V>,
+}
+
+impl<'a, K: SimpleKey + Send, V: Send> ParallelIterator for ParValuesMut<'a, K, V> {
+    type Item = &'a mut V;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        self.inner.map(|(_, val)| val).drive_unindexed(consumer)
+    }
+}
+
+pub struct ParIntoIter<K: SimpleKey, V: Send> {
+    inner: vec::IntoIter<Option<V>>,
+    phantom: PhantomData<K>,
+}
+
+impl<K: SimpleKey + Send, V: Send> ParallelIterator for ParIntoIter<K, V> {
+    type Item = (K, V);
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        self.inner
+            .enumerate()
+            .filter_map(|(i, opt)| {
+                opt.map(|val| {
+                    (
+                        unsafe {
+                            // SAFETY: The iterator only returns elements which are present and
+                            //          elements can't be removed from the `SimpleSurotto`, thus the creation of the key is safe here.
+                            K::new(i)
+                        },
+                        val,
+                    )
+                })
+            })
+            .drive_unindexed(consumer)
+    }
+}
+
+impl<K: SimpleKey + Send, V: Send> IntoParallelIterator for SimpleAssocSurotto<K, V> {
+    type Iter = ParIntoIter<K, V>;
+    type Item = (K, V);
+
+    fn into_par_iter(self) -> Self::Iter {
+        ParIntoIter {
+            inner: self.inner.into_par_iter(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, K: SimpleKey + Send, V: Sync> IntoParallelIterator for &'a SimpleAssocSurotto<K, V> {
+    type Iter = ParIter<'a, K, V>;
+    type Item = (K, &'a V);
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.par_iter()
+    }
+}
+
+impl<'a, K: SimpleKey + Send, V: Send> IntoParallelIterator for &'a mut SimpleAssocSurotto<K, V> {
+    type Iter = ParIterMut<'a, K, V>;
+    type Item = (K, &'a mut V);
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.par_iter_mut()
+    }
+}
+
+impl<K: SimpleKey + Send, V: Send> ParallelExtend<(K, V)> for SimpleAssocSurotto<K, V> {
+    /// Extends the surotto with the key-value pairs of a parallel iterator.
+    ///
+    /// The pairs are inserted in the order of the iterator, so when a key
+    /// occurs multiple times, the last value wins.
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = (K, V)>,
+    {
+        let pairs: Vec<(K, V)> = par_iter.into_par_iter().collect();
+        for (key, value) in pairs {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K: SimpleKey + Send, V: Send> FromParallelIterator<(K, V)> for SimpleAssocSurotto<K, V> {
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = (K, V)>,
+    {
+        let mut surotto = Self::new();
+        surotto.par_extend(par_iter);
+        surotto
+    }
+}
@@ -0,0 +1,82 @@
+use std::marker::PhantomData;
+
+use serde::{
+    de::{DeserializeSeed, Error},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::simple::{SimpleKey, SimpleSurotto};
+
+use super::SimpleAssocSurotto;
+
+impl<K: SimpleKey, V: Serialize> Serialize for SimpleAssocSurotto<K, V> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.inner.serialize(serializer)
+    }
+}
+
+impl<K: SimpleKey, V> SimpleAssocSurotto<K, V> {
+    /// Deserializes a surotto associated with the primary surotto.
+    ///
+    /// See [`AssocSeed`] for details.
+    pub fn deserialize_for<'de, D, P>(
+        primary: &SimpleSurotto<K, P>,
+        deserializer: D,
+    ) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+        V: Deserialize<'de>,
+    {
+        AssocSeed::new(primary).deserialize(deserializer)
+    }
+}
+
+/// Deserializes a [`SimpleAssocSurotto`] associated with a primary [`SimpleSurotto`].
+///
+/// Data with a value for an index the primary surotto doesn't contain is rejected,
+/// as no key could exist for it.
+pub struct AssocSeed<'a, K: SimpleKey, P, V> {
+    primary: &'a SimpleSurotto<K, P>,
+    phantom: PhantomData<V>,
+}
+
+impl<'a, K: SimpleKey, P, V> AssocSeed<'a, K, P, V> {
+    /// Constructs a new `AssocSeed` checking against the primary surotto.
+    pub fn new(primary: &'a SimpleSurotto<K, P>) -> Self {
+        Self {
+            primary,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, 'de, K: SimpleKey, P, V: Deserialize<'de>> DeserializeSeed<'de>
+    for AssocSeed<'a, K, P, V>
+{
+    type Value = SimpleAssocSurotto<K, V>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut inner = Vec::<Option<V>>::deserialize(deserializer)?;
+        if let Some(idx) = inner.iter().rposition(Option::is_some) {
+            if idx >= self.primary.len() {
+                return Err(D::Error::custom(format_args!(
+                    "slot {idx} is occupied, but the primary surotto only has {} keys",
+                    self.primary.len()
+                )));
+            }
+        }
+        inner.truncate(self.primary.len());
+        let len = inner.iter().filter(|slot| slot.is_some()).count();
+        Ok(SimpleAssocSurotto {
+            inner,
+            len,
+            phantom: PhantomData,
+        })
+    }
+}
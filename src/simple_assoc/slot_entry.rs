@@ -0,0 +1,147 @@
+use std::cell::Cell;
+
+use crate::{simple::SimpleKey, unchecked};
+
+/// A view into a single slot of a surotto, which may either be vacant or occupied.
+///
+/// Unlike an [`Entry`], it only borrows its own slot, so many of them can be alive at once.
+/// This `enum` is yielded by the [`iter_entries_for`] method on [`SimpleAssocSurotto`].
+///
+/// [`Entry`]: super::entry::Entry
+/// [`iter_entries_for`]: super::SimpleAssocSurotto::iter_entries_for
+/// [`SimpleAssocSurotto`]: super::SimpleAssocSurotto
+pub enum SlotEntry<'a, K: SimpleKey, V> {
+    /// An occupied slot.
+    Occupied(OccupiedSlotEntry<'a, K, V>),
+
+    /// A vacant slot.
+    Vacant(VacantSlotEntry<'a, K, V>),
+}
+
+pub struct OccupiedSlotEntry<'a, K: SimpleKey, V> {
+    pub(super) slot: &'a mut Option<V>,
+    /// The length of the surotto, shared by all slot entries of one iterator.
+    pub(super) len: &'a Cell<usize>,
+    pub(super) key: K,
+}
+
+pub struct VacantSlotEntry<'a, K: SimpleKey, V> {
+    pub(super) slot: &'a mut Option<V>,
+    /// The length of the surotto, shared by all slot entries of one iterator.
+    pub(super) len: &'a Cell<usize>,
+    pub(super) key: K,
+}
+
+impl<'a, K: SimpleKey, V> SlotEntry<'a, K, V> {
+    /// Ensures a value is in the slot by inserting the default if empty, and returns
+    /// a mutable reference to the value in the slot.
+    pub fn or_insert(self, val: V) -> &'a mut V {
+        self.or_insert_with(|| val)
+    }
+
+    /// Ensures a value is in the slot by inserting the result of the default function if empty,
+    /// and returns a mutable reference to the value in the slot.
+    pub fn or_insert_with<F>(self, f: F) -> &'a mut V
+    where
+        F: FnOnce() -> V,
+    {
+        match self {
+            SlotEntry::Occupied(o) => o.into_mut(),
+            SlotEntry::Vacant(v) => v.insert(f()),
+        }
+    }
+
+    /// Returns this slot's key.
+    pub fn key(&self) -> K {
+        match self {
+            SlotEntry::Occupied(o) => o.key,
+            SlotEntry::Vacant(v) => v.key,
+        }
+    }
+
+    /// Provides in-place mutable access to an occupied slot before any
+    /// potential inserts into the surotto.
+    pub fn and_modify<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&mut V),
+    {
+        match self {
+            SlotEntry::Occupied(mut entry) => {
+                f(entry.get_mut());
+                SlotEntry::Occupied(entry)
+            }
+            SlotEntry::Vacant(entry) => SlotEntry::Vacant(entry),
+        }
+    }
+}
+
+impl<'a, K: SimpleKey, V: Default> SlotEntry<'a, K, V> {
+    /// Ensures a value is in the slot by inserting the default value if empty,
+    /// and returns a mutable reference to the value in the slot.
+    pub fn or_default(self) -> &'a mut V {
+        self.or_insert_with(Default::default)
+    }
+}
+
+impl<'a, K: SimpleKey, V> OccupiedSlotEntry<'a, K, V> {
+    /// Returns this slot's key.
+    pub fn key(&self) -> K {
+        self.key
+    }
+
+    /// Gets a reference to the value in the slot.
+    pub fn get(&self) -> &V {
+        unsafe { unchecked::unwrap(self.slot.as_ref()) }
+    }
+
+    /// Gets a mutable reference to the value in the slot.
+    ///
+    /// If you need a reference which may outlive the destruction of the
+    /// `SlotEntry` value, see [`into_mut`].
+    ///
+    /// [`into_mut`]: Self::into_mut
+    pub fn get_mut(&mut self) -> &mut V {
+        unsafe { unchecked::unwrap(self.slot.as_mut()) }
+    }
+
+    /// Converts the `OccupiedSlotEntry` into a mutable reference to the value in the slot
+    /// with a lifetime bound to the surotto itself.
+    pub fn into_mut(self) -> &'a mut V {
+        unsafe { unchecked::unwrap(self.slot.as_mut()) }
+    }
+
+    /// Sets the value of the slot, and returns the slot's old value.
+    pub fn insert(&mut self, value: V) -> V {
+        unsafe { unchecked::unwrap(self.slot.replace(value)) }
+    }
+
+    /// Takes the value out of the slot, and returns it.
+    pub fn remove(self) -> V {
+        self.len.set(self.len.get() - 1);
+        unsafe { unchecked::unwrap(self.slot.take()) }
+    }
+}
+
+impl<'a, K: SimpleKey, V> VacantSlotEntry<'a, K, V> {
+    /// Returns this slot's key.
+    pub fn key(&self) -> K {
+        self.key
+    }
+
+    /// Sets the value of the slot, and returns a mutable reference to it.
+    pub fn insert(self, value: V) -> &'a mut V {
+        self.len.set(self.len.get() + 1);
+        self.slot.insert(value)
+    }
+
+    /// Sets the value of the slot, and returns an `OccupiedSlotEntry` for further manipulation.
+    pub fn insert_entry(self, value: V) -> OccupiedSlotEntry<'a, K, V> {
+        self.len.set(self.len.get() + 1);
+        *self.slot = Some(value);
+        OccupiedSlotEntry {
+            slot: self.slot,
+            len: self.len,
+            key: self.key,
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use std::mem;
+
+use crate::simple::SimpleKey;
+
+use super::SimpleAssocSurotto;
+
+/// Memory usage of a [`SimpleAssocSurotto`], see [`SimpleAssocSurotto::stats`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    /// The number of slots holding a value.
+    pub occupied: usize,
+    /// The number of slots without a value.
+    pub vacant: usize,
+    /// The index of the highest slot holding a value.
+    pub highest_occupied: Option<usize>,
+    /// The number of bytes allocated for slots, including unused capacity.
+    pub bytes_allocated: usize,
+    /// The share of vacant slots, from `0.0` to `1.0`.
+    pub fragmentation: f64,
+}
+
+impl<K: SimpleKey, V> SimpleAssocSurotto<K, V> {
+    /// Collects statistics about the memory usage, e.g. to decide when to shrink.
+    ///
+    /// This visits every slot.
+    pub fn stats(&self) -> Stats {
+        let occupied = self.len;
+        let vacant = self.inner.len() - occupied;
+        Stats {
+            occupied,
+            vacant,
+            highest_occupied: self.inner.iter().rposition(Option::is_some),
+            bytes_allocated: self.inner.capacity() * mem::size_of::<Option<V>>(),
+            fragmentation: if self.inner.is_empty() {
+                0.0
+            } else {
+                vacant as f64 / self.inner.len() as f64
+            },
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use crate::simple::SimpleKey;
+
+use super::SimpleConcurrentSurotto;
+
+pub struct Iter<'a, K: SimpleKey, V> {
+    pub(super) surotto: &'a SimpleConcurrentSurotto<K, V>,
+    pub(super) idx: usize,
+    pub(super) end: usize,
+}
+
+impl<'a, K: SimpleKey, V> Iterator for Iter<'a, K, V> {
+    type Item = (K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.idx < self.end {
+            let i = self.idx;
+            self.idx += 1;
+            if let Some(val) = self.surotto.buckets.get(i) {
+                return Some((
+                    unsafe {
+                        // SAFETY: The value was written, so the key was handed out by the insertion.
+                        K::new(i)
+                    },
+                    val,
+                ));
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.end - self.idx))
+    }
+}
+
+pub struct Keys<'a, K: SimpleKey, V> {
+    pub(super) inner: Iter<'a, K, V>,
+}
+
+impl<'a, K: SimpleKey, V> Iterator for Keys<'a, K, V> {
+    type Item = K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(key, _)| key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+pub struct Values<'a, K: SimpleKey, V> {
+    pub(super) inner: Iter<'a, K, V>,
+}
+
+impl<'a, K: SimpleKey, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, val)| val)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
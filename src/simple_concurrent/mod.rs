@@ -0,0 +1,194 @@
+use std::{
+    fmt,
+    marker::PhantomData,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use crate::{simple::SimpleKey, unchecked};
+
+use self::iterators::{Iter, Keys, Values};
+
+pub mod iterators;
+
+#[cfg(not(feature = "no-unsafe"))]
+mod raw_buckets;
+#[cfg(not(feature = "no-unsafe"))]
+use self::raw_buckets::Buckets;
+
+#[cfg(feature = "no-unsafe")]
+mod safe_buckets;
+#[cfg(feature = "no-unsafe")]
+use self::safe_buckets::Buckets;
+
+/// The length of the first bucket, every following bucket is twice as long.
+const FIRST_BUCKET_LEN: usize = 32;
+const SKIPPED_BITS: u32 = FIRST_BUCKET_LEN.trailing_zeros();
+const BUCKETS: usize = (usize::BITS - SKIPPED_BITS) as usize;
+
+/// An insert-only variant of [`SimpleSurotto`] which values can be inserted into
+/// from many threads at once.
+///
+/// Values are stored in buckets of growing size which are never moved,
+/// so inserting only needs `&self` and never invalidates references to other values.
+/// Getting a value is wait-free.
+///
+/// With the `no-unsafe` feature, every slot is a [`OnceLock`] instead of
+/// uninitialized memory behind raw pointers.
+///
+/// [`SimpleSurotto`]: crate::simple::SimpleSurotto
+/// [`OnceLock`]: std::sync::OnceLock
+pub struct SimpleConcurrentSurotto<K: SimpleKey, V> {
+    buckets: Buckets<V>,
+    /// The amount of started insertions, i.e. the next index to hand out.
+    inflight: AtomicUsize,
+    /// The amount of finished insertions.
+    count: AtomicUsize,
+    phantom: PhantomData<K>,
+}
+
+impl<K: SimpleKey, V> SimpleConcurrentSurotto<K, V> {
+    /// Constructs a new, empty `SimpleConcurrentSurotto<K, V>`.
+    ///
+    /// The surotto will not allocate until elements are inserted.
+    pub const fn new() -> Self {
+        Self {
+            buckets: Buckets::new(),
+            inflight: AtomicUsize::new(0),
+            count: AtomicUsize::new(0),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Inserts a value into the surotto, returning its key.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the key index is `usize::MAX`.
+    pub fn insert(&self, value: V) -> K {
+        self.reserve_key().fill(value)
+    }
+
+    /// Claims the slot for the next key without inserting a value yet.
+    ///
+    /// The key is handed out once the value is given with [`ReservedKey::fill`].
+    /// Until then, the slot is skipped by the iterators. When the reserved key is dropped
+    /// without being filled, the slot stays empty for good and doesn't count towards [`len`].
+    ///
+    /// [`len`]: Self::len
+    pub fn reserve_key(&self) -> ReservedKey<'_, K, V> {
+        ReservedKey {
+            surotto: self,
+            idx: self.inflight.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    pub fn get(&self, key: K) -> &V {
+        unsafe {
+            // SAFETY: The caller assures that the keys are only from this surotto.
+            //          Keys are returned after their value was written and values can't be removed.
+            unchecked::unwrap(self.buckets.get(key.idx()))
+        }
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key.
+    pub fn get_mut(&mut self, key: K) -> &mut V {
+        unsafe {
+            // SAFETY: The caller assures that the keys are only from this surotto.
+            //          Keys are returned after their value was written and values can't be removed.
+            unchecked::unwrap(self.buckets.get_mut(key.idx()))
+        }
+    }
+
+    /// Returns the number of elements in the surotto.
+    ///
+    /// While other threads insert, this is a snapshot which might already be outdated.
+    pub fn len(&self) -> usize {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    /// Returns `true` if the surotto contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// An iterator visiting all key-value pairs in order.
+    /// The iterator element type is `(K, &'a V)`.
+    ///
+    /// Values which are inserted while iterating might be skipped.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            surotto: self,
+            idx: 0,
+            end: self.inflight.load(Ordering::Relaxed),
+        }
+    }
+
+    /// An iterator visiting all keys in order.
+    /// The iterator element type is `K`.
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys { inner: self.iter() }
+    }
+
+    /// An iterator visiting all values in order.
+    /// The iterator element type is `&'a V`.
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values { inner: self.iter() }
+    }
+}
+
+/// A slot claimed by [`SimpleConcurrentSurotto::reserve_key`] which is waiting for its value.
+#[must_use = "the slot stays empty when the reserved key isn't filled"]
+pub struct ReservedKey<'a, K: SimpleKey, V> {
+    surotto: &'a SimpleConcurrentSurotto<K, V>,
+    idx: usize,
+}
+
+impl<'a, K: SimpleKey, V> ReservedKey<'a, K, V> {
+    /// Returns the index the key will have.
+    pub fn idx(&self) -> usize {
+        self.idx
+    }
+
+    /// Writes the value into the claimed slot, returning its key.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the key index is `usize::MAX`.
+    pub fn fill(self, value: V) -> K {
+        let key = unsafe {
+            // SAFETY: The index was handed out to us only, and the key is returned
+            //          after the value was written, so it can't be used before.
+            K::new(self.idx)
+        };
+        unsafe {
+            // SAFETY: No other insertion writes to this slot as the index is unique.
+            self.surotto.buckets.write(self.idx, value);
+        }
+        self.surotto.count.fetch_add(1, Ordering::Relaxed);
+        key
+    }
+}
+
+impl<K: SimpleKey + fmt::Debug, V: fmt::Debug> fmt::Debug for SimpleConcurrentSurotto<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: SimpleKey, V> Default for SimpleConcurrentSurotto<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the bucket and the offset inside of it for the index.
+fn location(idx: usize) -> (usize, usize) {
+    let idx = idx + FIRST_BUCKET_LEN;
+    let bucket = (usize::BITS - 1 - idx.leading_zeros() - SKIPPED_BITS) as usize;
+    (bucket, idx - bucket_len(bucket))
+}
+
+fn bucket_len(bucket: usize) -> usize {
+    FIRST_BUCKET_LEN << bucket
+}
@@ -0,0 +1,156 @@
+use std::{
+    cell::UnsafeCell,
+    mem::MaybeUninit,
+    ptr::{self, NonNull},
+    sync::atomic::{AtomicBool, AtomicPtr, Ordering},
+};
+
+use super::{bucket_len, location, BUCKETS};
+
+/// Buckets of uninitialized slots behind raw pointers.
+pub(super) struct Buckets<V> {
+    buckets: [AtomicPtr<Entry<V>>; BUCKETS],
+}
+
+struct Entry<V> {
+    slot: UnsafeCell<MaybeUninit<V>>,
+    active: AtomicBool,
+}
+
+impl<V> Drop for Entry<V> {
+    fn drop(&mut self) {
+        if *self.active.get_mut() {
+            unsafe {
+                // SAFETY: The slot is only marked active after the value was written.
+                self.slot.get_mut().assume_init_drop();
+            }
+        }
+    }
+}
+
+// SAFETY: Values are only handed out by reference, so sharing the buckets requires
+//          sharing the values, and writing from another thread requires sending them.
+unsafe impl<V: Send + Sync> Sync for Buckets<V> {}
+unsafe impl<V: Send> Send for Buckets<V> {}
+
+impl<V> Buckets<V> {
+    pub(super) const fn new() -> Self {
+        Self {
+            buckets: [const { AtomicPtr::new(ptr::null_mut()) }; BUCKETS],
+        }
+    }
+
+    /// Returns the value at the index, if it was written yet.
+    pub(super) fn get(&self, idx: usize) -> Option<&V> {
+        let (bucket, offset) = location(idx);
+        let entries = self.buckets[bucket].load(Ordering::Acquire);
+        if entries.is_null() {
+            return None;
+        }
+        unsafe {
+            // SAFETY: The bucket is allocated and long enough for the offset.
+            let entry = &*entries.add(offset);
+            // SAFETY: The slot is only marked active after the value was written.
+            entry
+                .active
+                .load(Ordering::Acquire)
+                .then(|| (*entry.slot.get()).assume_init_ref())
+        }
+    }
+
+    /// Returns the value at the index mutably, if it was written yet.
+    pub(super) fn get_mut(&mut self, idx: usize) -> Option<&mut V> {
+        let (bucket, offset) = location(idx);
+        let entries = NonNull::new(*self.buckets[bucket].get_mut())?;
+        unsafe {
+            // SAFETY: The bucket is allocated and long enough for the offset,
+            //          and we are borrowed mutably, so nobody else accesses the entry.
+            let entry = &mut *entries.as_ptr().add(offset);
+            // SAFETY: The slot is only marked active after the value was written.
+            if *entry.active.get_mut() {
+                Some(entry.slot.get_mut().assume_init_mut())
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Writes the value into the slot at the index.
+    ///
+    /// # Safety
+    ///
+    /// Every index may only be written once.
+    pub(super) unsafe fn write(&self, idx: usize, value: V) {
+        let (bucket, offset) = location(idx);
+        let entries = self.bucket_or_alloc(bucket);
+        // SAFETY: The bucket is long enough for the offset, and the caller
+        //          assures that no other write goes to this slot.
+        let entry = &*entries.as_ptr().add(offset);
+        (*entry.slot.get()).write(value);
+        entry.active.store(true, Ordering::Release);
+    }
+
+    fn bucket_or_alloc(&self, bucket: usize) -> NonNull<Entry<V>> {
+        let entries = self.buckets[bucket].load(Ordering::Acquire);
+        if let Some(entries) = NonNull::new(entries) {
+            return entries;
+        }
+
+        let new = alloc_bucket::<V>(bucket);
+        match self.buckets[bucket].compare_exchange(
+            ptr::null_mut(),
+            new.as_ptr(),
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => new,
+            Err(other) => {
+                unsafe {
+                    // SAFETY: Another thread was faster, so our bucket was never shared.
+                    dealloc_bucket(new, bucket);
+                }
+                unsafe {
+                    // SAFETY: The exchange only fails when the bucket was set.
+                    NonNull::new_unchecked(other)
+                }
+            }
+        }
+    }
+}
+
+impl<V> Drop for Buckets<V> {
+    fn drop(&mut self) {
+        for (bucket, entries) in self.buckets.iter_mut().enumerate() {
+            if let Some(entries) = NonNull::new(*entries.get_mut()) {
+                unsafe {
+                    // SAFETY: The bucket was allocated by us and nobody can access it anymore.
+                    dealloc_bucket(entries, bucket);
+                }
+            }
+        }
+    }
+}
+
+fn alloc_bucket<V>(bucket: usize) -> NonNull<Entry<V>> {
+    let entries: Box<[Entry<V>]> = (0..bucket_len(bucket))
+        .map(|_| Entry {
+            slot: UnsafeCell::new(MaybeUninit::uninit()),
+            active: AtomicBool::new(false),
+        })
+        .collect();
+    let entries = Box::into_raw(entries) as *mut Entry<V>;
+    unsafe {
+        // SAFETY: Pointers from a box are never null.
+        NonNull::new_unchecked(entries)
+    }
+}
+
+/// # Safety
+///
+/// The entries must come from [`alloc_bucket`] with the same bucket.
+unsafe fn dealloc_bucket<V>(entries: NonNull<Entry<V>>, bucket: usize) {
+    drop(Box::from_raw(ptr::slice_from_raw_parts_mut(
+        entries.as_ptr(),
+        bucket_len(bucket),
+    )));
+}
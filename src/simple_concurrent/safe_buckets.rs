@@ -0,0 +1,42 @@
+use std::sync::OnceLock;
+
+use super::{bucket_len, location, BUCKETS};
+
+/// Buckets where every slot is a [`OnceLock`], used with the `no-unsafe` feature.
+pub(super) struct Buckets<V> {
+    buckets: [OnceLock<Box<[OnceLock<V>]>>; BUCKETS],
+}
+
+impl<V> Buckets<V> {
+    pub(super) const fn new() -> Self {
+        Self {
+            buckets: [const { OnceLock::new() }; BUCKETS],
+        }
+    }
+
+    /// Returns the value at the index, if it was written yet.
+    pub(super) fn get(&self, idx: usize) -> Option<&V> {
+        let (bucket, offset) = location(idx);
+        self.buckets[bucket].get()?[offset].get()
+    }
+
+    /// Returns the value at the index mutably, if it was written yet.
+    pub(super) fn get_mut(&mut self, idx: usize) -> Option<&mut V> {
+        let (bucket, offset) = location(idx);
+        self.buckets[bucket].get_mut()?[offset].get_mut()
+    }
+
+    /// Writes the value into the slot at the index.
+    ///
+    /// # Safety
+    ///
+    /// Every index may only be written once.
+    pub(super) unsafe fn write(&self, idx: usize, value: V) {
+        let (bucket, offset) = location(idx);
+        let slots = self.buckets[bucket]
+            .get_or_init(|| (0..bucket_len(bucket)).map(|_| OnceLock::new()).collect());
+        if slots[offset].set(value).is_err() {
+            panic!("slot {idx} was written twice");
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use core::slice;
+
+use crate::simple::{iterators::Iter, SimpleKey};
+
+pub struct ChangedSince<'a, K: SimpleKey, V> {
+    pub(super) inner: Iter<'a, K, V>,
+    pub(super) ticks: slice::Iter<'a, u64>,
+    pub(super) tick: u64,
+}
+
+impl<'a, K: SimpleKey, V> Iterator for ChangedSince<'a, K, V> {
+    type Item = (K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for pair in self.inner.by_ref() {
+            let changed = *self.ticks.next()?;
+            if changed >= self.tick {
+                return Some(pair);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{
+    simple::{SimpleKey, SimpleSurotto},
+    unchecked,
+};
+
+use self::iterators::ChangedSince;
+
+pub mod iterators;
+
+/// A [`SimpleSurotto`] which records for every value the tick it was last changed at.
+///
+/// The tick is advanced manually, e.g. once per frame, and every insertion or
+/// mutable access marks the value with the current tick. Afterwards
+/// [`iter_changed_since`] yields only the values which were changed since then.
+///
+/// [`iter_changed_since`]: Self::iter_changed_since
+pub struct TrackedSurotto<K: SimpleKey, V> {
+    inner: SimpleSurotto<K, V>,
+    ticks: Vec<u64>,
+    tick: u64,
+}
+
+impl<K: SimpleKey, V> TrackedSurotto<K, V> {
+    /// Constructs a new, empty `TrackedSurotto<K, V>` at tick `0`.
+    pub const fn new() -> Self {
+        Self {
+            inner: SimpleSurotto::new(),
+            ticks: Vec::new(),
+            tick: 0,
+        }
+    }
+
+    /// Returns the current tick.
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
+    /// Advances to the next tick and returns it.
+    pub fn advance_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+
+    /// Inserts a value into the surotto, returning its key.
+    /// The value is marked as changed at the current tick.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the key index is `usize::MAX`.
+    pub fn insert(&mut self, value: V) -> K {
+        let key = self.inner.insert(value);
+        self.ticks.push(self.tick);
+        key
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    pub fn get(&self, key: K) -> &V {
+        self.inner.get(key)
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key
+    /// and marks it as changed at the current tick.
+    pub fn get_mut(&mut self, key: K) -> &mut V {
+        unsafe {
+            // SAFETY: The key is from our surotto, which has a tick for every value.
+            *unchecked::get_mut(&mut self.ticks, key.idx()) = self.tick;
+        }
+        self.inner.get_mut(key)
+    }
+
+    /// Returns the tick the value corresponding to the key was last changed at.
+    pub fn changed_at(&self, key: K) -> u64 {
+        unsafe {
+            // SAFETY: The key is from our surotto, which has a tick for every value.
+            *unchecked::get(&self.ticks, key.idx())
+        }
+    }
+
+    /// Returns the underlying surotto, to read values without marking them.
+    pub fn as_inner(&self) -> &SimpleSurotto<K, V> {
+        &self.inner
+    }
+
+    /// Returns the number of elements in the surotto.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns `true` if the surotto contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// An iterator visiting all key-value pairs which were changed at `tick` or later.
+    /// The iterator element type is `(K, &'a V)`.
+    pub fn iter_changed_since(&self, tick: u64) -> ChangedSince<'_, K, V> {
+        ChangedSince {
+            inner: self.inner.iter(),
+            ticks: self.ticks.iter(),
+            tick,
+        }
+    }
+}
+
+impl<K: SimpleKey, V> Default for TrackedSurotto<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,87 @@
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    marker::PhantomData,
+};
+
+use crate::{simple::SimpleKey, simple_assoc::SimpleAssocSurotto};
+
+/// A container holding one [`SimpleAssocSurotto<K, T>`] per type `T`.
+///
+/// The surottos are created lazily on first mutable access, so arbitrary typed data
+/// can be associated with the keys without knowing all types up front.
+///
+/// [`SimpleAssocSurotto<K, T>`]: SimpleAssocSurotto
+pub struct TypedStorage<K: SimpleKey + 'static> {
+    storages: HashMap<TypeId, Box<dyn Any>>,
+    phantom: PhantomData<K>,
+}
+
+impl<K: SimpleKey + 'static> TypedStorage<K> {
+    /// Constructs a new, empty `TypedStorage<K>`.
+    pub fn new() -> Self {
+        Self {
+            storages: HashMap::new(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the surotto for `T`, if it was created yet.
+    pub fn storage<T: 'static>(&self) -> Option<&SimpleAssocSurotto<K, T>> {
+        self.storages
+            .get(&TypeId::of::<T>())
+            .map(|storage| Self::downcast_ref(storage.as_ref()))
+    }
+
+    /// Returns a mutable reference to the surotto for `T`,
+    /// creating it if it doesn't exist yet.
+    pub fn storage_mut<T: 'static>(&mut self) -> &mut SimpleAssocSurotto<K, T> {
+        let storage = self
+            .storages
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::new(SimpleAssocSurotto::<K, T>::new()));
+        Self::downcast_mut(storage.as_mut())
+    }
+
+    /// Removes the surotto for `T` from the storage, returning it if it existed.
+    pub fn remove_storage<T: 'static>(&mut self) -> Option<SimpleAssocSurotto<K, T>> {
+        self.storages.remove(&TypeId::of::<T>()).map(|storage| {
+            *storage
+                .downcast()
+                .expect("storages are always stored under their own type id")
+        })
+    }
+
+    /// Returns true if a surotto for `T` was created.
+    pub fn contains_storage<T: 'static>(&self) -> bool {
+        self.storages.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Returns the number of surottos in the storage.
+    pub fn len(&self) -> usize {
+        self.storages.len()
+    }
+
+    /// Returns true if the storage contains no surottos.
+    pub fn is_empty(&self) -> bool {
+        self.storages.is_empty()
+    }
+
+    fn downcast_ref<T: 'static>(storage: &dyn Any) -> &SimpleAssocSurotto<K, T> {
+        storage
+            .downcast_ref()
+            .expect("storages are always stored under their own type id")
+    }
+
+    fn downcast_mut<T: 'static>(storage: &mut dyn Any) -> &mut SimpleAssocSurotto<K, T> {
+        storage
+            .downcast_mut()
+            .expect("storages are always stored under their own type id")
+    }
+}
+
+impl<K: SimpleKey + 'static> Default for TypedStorage<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
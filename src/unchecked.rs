@@ -0,0 +1,53 @@
+// Accessors which skip their checks for speed.
+// With the `no-unsafe` feature they are checked and panic instead.
+
+#[cfg(not(feature = "no-unsafe"))]
+pub(crate) unsafe fn get<T>(slice: &[T], idx: usize) -> &T {
+    slice.get_unchecked(idx)
+}
+
+#[cfg(feature = "no-unsafe")]
+pub(crate) unsafe fn get<T>(slice: &[T], idx: usize) -> &T {
+    &slice[idx]
+}
+
+#[cfg(not(feature = "no-unsafe"))]
+pub(crate) unsafe fn get_mut<T>(slice: &mut [T], idx: usize) -> &mut T {
+    slice.get_unchecked_mut(idx)
+}
+
+#[cfg(feature = "no-unsafe")]
+pub(crate) unsafe fn get_mut<T>(slice: &mut [T], idx: usize) -> &mut T {
+    &mut slice[idx]
+}
+
+#[cfg(not(feature = "no-unsafe"))]
+pub(crate) unsafe fn unwrap<T>(option: Option<T>) -> T {
+    option.unwrap_unchecked()
+}
+
+#[cfg(feature = "no-unsafe")]
+pub(crate) unsafe fn unwrap<T>(option: Option<T>) -> T {
+    option.expect("value must be present")
+}
+
+#[cfg(not(feature = "no-unsafe"))]
+pub(crate) unsafe fn get_disjoint_mut<T, I>(slice: &mut [T], indices: I) -> Vec<&mut T>
+where
+    I: IntoIterator<Item = usize>,
+{
+    let ptr = slice.as_mut_ptr();
+    indices.into_iter().map(|idx| &mut *ptr.add(idx)).collect()
+}
+
+#[cfg(feature = "no-unsafe")]
+pub(crate) unsafe fn get_disjoint_mut<T, I>(slice: &mut [T], indices: I) -> Vec<&mut T>
+where
+    I: IntoIterator<Item = usize>,
+{
+    let mut elements: Vec<Option<&mut T>> = slice.iter_mut().map(Some).collect();
+    indices
+        .into_iter()
+        .map(|idx| elements[idx].take().expect("indices must be distinct"))
+        .collect()
+}
@@ -0,0 +1,109 @@
+use std::cmp::Ordering;
+
+use crate::{simple::SimpleKey, simple_assoc::SimpleAssocSurotto};
+
+/// Disjoint sets of keys, merged with [`union`] and queried with [`find`].
+///
+/// Every key starts out in a set of its own, so no key has to be added up front.
+/// Only keys which were part of a [`union`] are stored.
+///
+/// [`union`]: Self::union
+/// [`find`]: Self::find
+pub struct UnionFind<K: SimpleKey> {
+    nodes: SimpleAssocSurotto<K, Node<K>>,
+}
+
+#[derive(Clone, Copy)]
+struct Node<K> {
+    parent: K,
+    rank: u32,
+}
+
+impl<K: SimpleKey> UnionFind<K> {
+    /// Constructs a new `UnionFind<K>` where every key is in a set of its own.
+    pub const fn new() -> Self {
+        Self {
+            nodes: SimpleAssocSurotto::new(),
+        }
+    }
+
+    /// Returns the representative key of the set containing the key.
+    ///
+    /// The path to the representative gets compressed along the way.
+    pub fn find(&mut self, key: K) -> K {
+        let mut root = key;
+        while let Some(node) = self.nodes.get(root) {
+            if node.parent.idx() == root.idx() {
+                break;
+            }
+            root = node.parent;
+        }
+
+        let mut current = key;
+        while current.idx() != root.idx() {
+            let node = self
+                .nodes
+                .get_mut(current)
+                .expect("key is on the path to the root");
+            current = node.parent;
+            node.parent = root;
+        }
+        root
+    }
+
+    /// Merges the sets containing both keys.
+    ///
+    /// Returns `false` if they were already in the same set.
+    pub fn union(&mut self, a: K, b: K) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a.idx() == b.idx() {
+            return false;
+        }
+
+        let rank_a = self.node(a).rank;
+        let rank_b = self.node(b).rank;
+        match rank_a.cmp(&rank_b) {
+            Ordering::Less => self.node(a).parent = b,
+            Ordering::Greater => self.node(b).parent = a,
+            Ordering::Equal => {
+                self.node(b).parent = a;
+                self.node(a).rank += 1;
+            }
+        }
+        true
+    }
+
+    /// Returns `true` if both keys are in the same set.
+    pub fn connected(&mut self, a: K, b: K) -> bool {
+        self.find(a).idx() == self.find(b).idx()
+    }
+
+    /// Returns all sets with more than one key, each in index order.
+    pub fn classes(&mut self) -> Vec<Vec<K>> {
+        let keys: Vec<K> = self.nodes.keys().collect();
+        let mut positions = SimpleAssocSurotto::<K, usize>::new();
+        let mut classes = Vec::new();
+        for key in keys {
+            let root = self.find(key);
+            let pos = *positions.entry(root).or_insert_with(|| {
+                classes.push(Vec::new());
+                classes.len() - 1
+            });
+            classes[pos].push(key);
+        }
+        classes
+    }
+
+    fn node(&mut self, key: K) -> &mut Node<K> {
+        self.nodes.entry(key).or_insert(Node {
+            parent: key,
+            rank: 0,
+        })
+    }
+}
+
+impl<K: SimpleKey> Default for UnionFind<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
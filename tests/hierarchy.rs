@@ -0,0 +1,100 @@
+use surotto::{hierarchy::Hierarchy, simple::SimpleSurotto, simple_key};
+
+simple_key!(
+    struct Key;
+);
+
+/// Builds the tree
+///
+/// ```text
+/// 0
+/// ├── 1
+/// │   ├── 3
+/// │   └── 4
+/// └── 2
+///     └── 5
+/// ```
+fn setup() -> (Hierarchy<Key>, Vec<Key>) {
+    let mut primary = SimpleSurotto::<Key, ()>::new();
+    let keys: Vec<Key> = (0..7).map(|_| primary.insert(())).collect();
+
+    let mut hierarchy = Hierarchy::new();
+    for (child, parent) in [(1, 0), (2, 0), (3, 1), (4, 1), (5, 2)] {
+        assert!(hierarchy.attach(keys[child], keys[parent]));
+    }
+    (hierarchy, keys)
+}
+
+fn indices(keys: &[Key], visited: impl IntoIterator<Item = Key>) -> Vec<usize> {
+    visited
+        .into_iter()
+        .map(|key| keys.iter().position(|&k| k == key).unwrap())
+        .collect()
+}
+
+#[test]
+fn links() {
+    let (hierarchy, keys) = setup();
+    assert_eq!(hierarchy.parent(keys[3]), Some(keys[1]));
+    assert_eq!(hierarchy.parent(keys[0]), None);
+    assert_eq!(hierarchy.children(keys[1]), [keys[3], keys[4]]);
+    assert_eq!(hierarchy.children(keys[6]), []);
+
+    assert!(hierarchy.is_ancestor_or_self(keys[0], keys[5]));
+    assert!(hierarchy.is_ancestor_or_self(keys[5], keys[5]));
+    assert!(!hierarchy.is_ancestor_or_self(keys[1], keys[5]));
+}
+
+#[test]
+fn traversal_order() {
+    let (hierarchy, keys) = setup();
+    assert_eq!(
+        indices(&keys, hierarchy.depth_first(keys[0])),
+        [0, 1, 3, 4, 2, 5]
+    );
+    assert_eq!(
+        indices(&keys, hierarchy.breadth_first(keys[0])),
+        [0, 1, 2, 3, 4, 5]
+    );
+    assert_eq!(indices(&keys, hierarchy.depth_first(keys[6])), [6]);
+}
+
+#[test]
+fn attach_rejects_cycles() {
+    let (mut hierarchy, keys) = setup();
+    assert!(!hierarchy.attach(keys[0], keys[3]));
+    assert!(!hierarchy.attach(keys[2], keys[2]));
+    assert_eq!(hierarchy.parent(keys[0]), None);
+}
+
+#[test]
+fn reattach_and_detach() {
+    let (mut hierarchy, keys) = setup();
+
+    assert!(hierarchy.attach(keys[1], keys[5]));
+    assert_eq!(hierarchy.children(keys[0]), [keys[2]]);
+    assert_eq!(hierarchy.parent(keys[1]), Some(keys[5]));
+    assert_eq!(
+        indices(&keys, hierarchy.depth_first(keys[0])),
+        [0, 2, 5, 1, 3, 4]
+    );
+
+    assert_eq!(hierarchy.detach(keys[2]), Some(keys[0]));
+    assert_eq!(hierarchy.detach(keys[2]), None);
+    assert_eq!(hierarchy.children(keys[0]), []);
+    // The children of a detached key stay attached to it.
+    assert_eq!(hierarchy.children(keys[2]), [keys[5]]);
+}
+
+#[test]
+fn remove_subtree() {
+    let (mut hierarchy, keys) = setup();
+    assert_eq!(indices(&keys, hierarchy.remove_subtree(keys[1])), [1, 3, 4]);
+
+    assert_eq!(hierarchy.children(keys[0]), [keys[2]]);
+    for i in [1, 3, 4] {
+        assert_eq!(hierarchy.parent(keys[i]), None);
+        assert_eq!(hierarchy.children(keys[i]), []);
+    }
+    assert_eq!(indices(&keys, hierarchy.depth_first(keys[0])), [0, 2, 5]);
+}
@@ -0,0 +1,156 @@
+use surotto::{
+    join::{join, maybe},
+    simple::SimpleSurotto,
+    simple_assoc::SimpleAssocSurotto,
+    simple_key,
+};
+
+simple_key!(
+    struct Key;
+);
+
+struct World {
+    names: SimpleSurotto<Key, &'static str>,
+    positions: SimpleAssocSurotto<Key, i32>,
+    velocities: SimpleAssocSurotto<Key, i32>,
+    keys: Vec<Key>,
+}
+
+/// A primary surotto of five names, with positions for every other key and
+/// velocities for the keys 1, 2 and 4.
+fn setup() -> World {
+    let mut names = SimpleSurotto::new();
+    let keys: Vec<Key> = ["a", "b", "c", "d", "e"]
+        .into_iter()
+        .map(|name| names.insert(name))
+        .collect();
+
+    let mut positions = SimpleAssocSurotto::new();
+    for &i in &[0, 2, 4] {
+        positions.insert(keys[i], i as i32 * 10);
+    }
+    let mut velocities = SimpleAssocSurotto::new();
+    for &i in &[1, 2, 4] {
+        velocities.insert(keys[i], i as i32);
+    }
+    World {
+        names,
+        positions,
+        velocities,
+        keys,
+    }
+}
+
+#[test]
+fn primary_only() {
+    let World { names, keys, .. } = setup();
+    let joined: Vec<_> = join((&names,)).collect();
+    assert_eq!(joined.len(), 5);
+    assert_eq!(joined[3], (keys[3], &"d"));
+}
+
+#[test]
+fn skips_missing_keys() {
+    let World {
+        names,
+        positions,
+        velocities,
+        keys,
+    } = setup();
+
+    let joined: Vec<_> = join((&names, &positions)).collect();
+    assert_eq!(
+        joined,
+        [
+            (keys[0], &"a", &0),
+            (keys[2], &"c", &20),
+            (keys[4], &"e", &40)
+        ]
+    );
+
+    let joined: Vec<_> = join((&names, &positions, &velocities))
+        .map(|(key, _, _, _)| key)
+        .collect();
+    assert_eq!(joined, [keys[2], keys[4]]);
+}
+
+#[test]
+fn assoc_primary() {
+    let World {
+        names,
+        positions,
+        velocities,
+        keys,
+    } = setup();
+    let joined: Vec<_> = join((&velocities, &names, &positions))
+        .map(|(key, &vel, &name, &pos)| (key, vel, name, pos))
+        .collect();
+    assert_eq!(joined, [(keys[2], 2, "c", 20), (keys[4], 4, "e", 40)]);
+}
+
+#[test]
+fn maybe_keeps_missing_keys() {
+    let World {
+        names,
+        positions,
+        velocities,
+        keys,
+    } = setup();
+
+    let joined: Vec<_> = join((&names, maybe(&positions)))
+        .map(|(_, _, pos)| pos.copied())
+        .collect();
+    assert_eq!(joined, [Some(0), None, Some(20), None, Some(40)]);
+
+    // A shorter source still yields `None` past its last slot.
+    let joined: Vec<_> = join((&names, &velocities, maybe(&positions)))
+        .map(|(key, _, _, pos)| (key, pos.copied()))
+        .collect();
+    assert_eq!(
+        joined,
+        [(keys[1], None), (keys[2], Some(20)), (keys[4], Some(40))]
+    );
+}
+
+#[test]
+fn mutable_sources() {
+    let World {
+        mut names,
+        mut positions,
+        velocities,
+        keys,
+    } = setup();
+
+    for (_, name, pos, vel) in join((&mut names, &mut positions, &velocities)) {
+        *pos += vel;
+        *name = "moved";
+    }
+    assert_eq!(positions.get(keys[2]), Some(&22));
+    assert_eq!(positions.get(keys[4]), Some(&44));
+    assert_eq!(positions.get(keys[0]), Some(&0));
+    assert_eq!(names.values().filter(|&&name| name == "moved").count(), 2);
+
+    for (_, _, pos) in join((&names, maybe(&mut positions))) {
+        if let Some(pos) = pos {
+            *pos = -1;
+        }
+    }
+    assert!(positions.values().all(|&pos| pos == -1));
+}
+
+#[test]
+fn three_secondary_sources() {
+    let World {
+        names,
+        positions,
+        velocities,
+        keys,
+    } = setup();
+    let mut tags = SimpleAssocSurotto::new();
+    tags.insert(keys[4], "tagged");
+
+    let joined: Vec<_> = join((&names, &positions, &velocities, &tags))
+        .map(|(key, &name, &pos, &vel, &tag)| (key, name, pos, vel, tag))
+        .collect();
+    assert_eq!(joined, [(keys[4], "e", 40, 4, "tagged")]);
+}
@@ -0,0 +1,166 @@
+use std::collections::VecDeque;
+
+use surotto::{key_list::KeyList, simple::SimpleSurotto, simple_key};
+
+simple_key!(
+    struct Key;
+);
+
+fn setup(len: usize) -> Vec<Key> {
+    let mut primary = SimpleSurotto::<Key, ()>::new();
+    (0..len).map(|_| primary.insert(())).collect()
+}
+
+/// Checks the list in both directions against the expected keys.
+fn assert_list(list: &KeyList<Key>, expected: &[Key]) {
+    assert_eq!(list.len(), expected.len());
+    assert_eq!(list.is_empty(), expected.is_empty());
+    assert_eq!(list.iter().len(), expected.len());
+    assert_eq!(list.iter().collect::<Vec<_>>(), expected);
+    assert_eq!(
+        list.iter().rev().collect::<Vec<_>>(),
+        expected.iter().rev().copied().collect::<Vec<_>>()
+    );
+    assert_eq!(list.front(), expected.first().copied());
+    assert_eq!(list.back(), expected.last().copied());
+    for pair in expected.windows(2) {
+        assert_eq!(list.next(pair[0]), Some(pair[1]));
+        assert_eq!(list.prev(pair[1]), Some(pair[0]));
+    }
+}
+
+#[test]
+fn push_and_pop() {
+    let keys = setup(4);
+    let mut list = KeyList::new();
+    assert_list(&list, &[]);
+
+    assert!(list.push_back(keys[1]));
+    assert!(list.push_back(keys[2]));
+    assert!(list.push_front(keys[0]));
+    assert!(!list.push_back(keys[0]));
+    assert_list(&list, &[keys[0], keys[1], keys[2]]);
+
+    assert_eq!(list.pop_front(), Some(keys[0]));
+    assert_eq!(list.pop_back(), Some(keys[2]));
+    assert_eq!(list.pop_back(), Some(keys[1]));
+    assert_eq!(list.pop_front(), None);
+    assert_list(&list, &[]);
+}
+
+#[test]
+fn insert_around_anchor() {
+    let keys = setup(5);
+    let mut list = KeyList::new();
+    list.push_back(keys[0]);
+    list.push_back(keys[4]);
+
+    assert!(list.insert_after(keys[0], keys[2]));
+    assert!(list.insert_before(keys[2], keys[1]));
+    assert!(list.insert_after(keys[2], keys[3]));
+    assert_list(&list, &[keys[0], keys[1], keys[2], keys[3], keys[4]]);
+
+    // Neither an anchor outside the list nor a key already in it is accepted.
+    list.remove(keys[4]);
+    assert!(!list.insert_after(keys[4], keys[0]));
+    assert!(!list.insert_before(keys[0], keys[3]));
+    assert_list(&list, &[keys[0], keys[1], keys[2], keys[3]]);
+
+    assert!(list.insert_after(keys[3], keys[4]));
+    assert_eq!(list.back(), Some(keys[4]));
+}
+
+#[test]
+fn remove_middle() {
+    let keys = setup(5);
+    let mut list = KeyList::new();
+    for &key in &keys {
+        list.push_back(key);
+    }
+
+    assert!(list.remove(keys[2]));
+    assert!(!list.remove(keys[2]));
+    assert!(!list.contains(keys[2]));
+    assert_eq!(list.next(keys[2]), None);
+    assert_list(&list, &[keys[0], keys[1], keys[3], keys[4]]);
+
+    assert!(list.remove(keys[0]));
+    assert!(list.remove(keys[4]));
+    assert_list(&list, &[keys[1], keys[3]]);
+
+    // A removed key can be inserted again.
+    assert!(list.push_front(keys[2]));
+    assert_list(&list, &[keys[2], keys[1], keys[3]]);
+}
+
+#[test]
+fn iterate_from_both_ends() {
+    let keys = setup(4);
+    let mut list = KeyList::new();
+    for &key in &keys {
+        list.push_back(key);
+    }
+
+    let mut iter = list.iter();
+    assert_eq!(iter.next(), Some(keys[0]));
+    assert_eq!(iter.next_back(), Some(keys[3]));
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.next_back(), Some(keys[2]));
+    assert_eq!(iter.next(), Some(keys[1]));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn against_a_model() {
+    let keys = setup(16);
+    let mut list = KeyList::new();
+    let mut model = VecDeque::new();
+
+    let mut state = 0x9e37_79b9_u32;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state as usize
+    };
+    for _ in 0..2000 {
+        let key = keys[next() % keys.len()];
+        let anchor = keys[next() % keys.len()];
+        let present = model.contains(&key);
+        let anchor_pos = model.iter().position(|&k| k == anchor);
+        match next() % 5 {
+            0 => {
+                assert_eq!(list.push_back(key), !present);
+                if !present {
+                    model.push_back(key);
+                }
+            }
+            1 => {
+                assert_eq!(list.push_front(key), !present);
+                if !present {
+                    model.push_front(key);
+                }
+            }
+            2 => {
+                let inserted = !present && anchor_pos.is_some();
+                assert_eq!(list.insert_after(anchor, key), inserted);
+                if let (true, Some(pos)) = (inserted, anchor_pos) {
+                    model.insert(pos + 1, key);
+                }
+            }
+            3 => {
+                let inserted = !present && anchor_pos.is_some();
+                assert_eq!(list.insert_before(anchor, key), inserted);
+                if let (true, Some(pos)) = (inserted, anchor_pos) {
+                    model.insert(pos, key);
+                }
+            }
+            _ => {
+                assert_eq!(list.remove(key), present);
+                model.retain(|&k| k != key);
+            }
+        }
+        assert_list(&list, model.make_contiguous());
+    }
+}
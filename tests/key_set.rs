@@ -0,0 +1,131 @@
+use std::collections::BTreeSet;
+
+use surotto::{key_set::KeySet, simple::SimpleKey, simple::SimpleSurotto, simple_key};
+
+simple_key!(
+    struct Key;
+);
+
+/// Spans several words, with the last one only partly used.
+const KEYS: usize = 200;
+
+fn setup() -> Vec<Key> {
+    let mut primary = SimpleSurotto::<Key, ()>::new();
+    (0..KEYS).map(|_| primary.insert(())).collect()
+}
+
+/// Checks the set, its count and its iteration order against the model.
+fn assert_set(set: &KeySet<Key>, model: &BTreeSet<usize>) {
+    assert_eq!(set.len(), model.len());
+    assert_eq!(set.is_empty(), model.is_empty());
+    assert_eq!(set.iter().len(), model.len());
+    assert_eq!(
+        set.iter().map(Key::idx).collect::<Vec<_>>(),
+        model.iter().copied().collect::<Vec<_>>()
+    );
+}
+
+fn build(keys: &[Key], filter: impl Fn(usize) -> bool) -> (KeySet<Key>, BTreeSet<usize>) {
+    let model: BTreeSet<usize> = (0..keys.len()).filter(|&i| filter(i)).collect();
+    let set = model.iter().map(|&i| keys[i]).collect();
+    (set, model)
+}
+
+#[test]
+fn insert_remove_contains() {
+    let keys = setup();
+    let mut set = KeySet::new();
+    assert_set(&set, &BTreeSet::new());
+
+    assert!(set.insert(keys[130]));
+    assert!(set.insert(keys[0]));
+    assert!(set.insert(keys[63]));
+    assert!(set.insert(keys[64]));
+    assert!(!set.insert(keys[64]));
+    assert_set(&set, &BTreeSet::from([0, 63, 64, 130]));
+
+    assert!(set.contains(keys[63]));
+    assert!(!set.contains(keys[62]));
+    // Keys beyond the highest inserted index are simply absent.
+    assert!(!set.contains(keys[199]));
+
+    assert!(set.remove(keys[63]));
+    assert!(!set.remove(keys[63]));
+    assert!(!set.remove(keys[199]));
+    assert_set(&set, &BTreeSet::from([0, 64, 130]));
+
+    set.clear();
+    assert_set(&set, &BTreeSet::new());
+    assert!(!set.contains(keys[130]));
+    assert!(set.insert(keys[130]));
+    assert_set(&set, &BTreeSet::from([130]));
+}
+
+#[test]
+fn set_operations() {
+    let keys = setup();
+    let filters: [fn(usize) -> bool; 5] = [
+        |i| i % 2 == 0,
+        |i| i % 3 == 0,
+        |i| i < 70,
+        |i| i > 120,
+        |_| false,
+    ];
+
+    for a in filters {
+        for b in filters {
+            let (set_a, model_a) = build(&keys, a);
+            let (set_b, model_b) = build(&keys, b);
+
+            let mut union = set_a.clone();
+            union.union_with(&set_b);
+            assert_set(&union, &(&model_a | &model_b));
+
+            let mut intersection = set_a.clone();
+            intersection.intersect_with(&set_b);
+            assert_set(&intersection, &(&model_a & &model_b));
+
+            let mut difference = set_a.clone();
+            difference.difference_with(&set_b);
+            assert_set(&difference, &(&model_a - &model_b));
+        }
+    }
+}
+
+#[test]
+fn operations_with_differently_sized_sets() {
+    let keys = setup();
+    let short: KeySet<Key> = [keys[1], keys[5]].into_iter().collect();
+    let long: KeySet<Key> = [keys[5], keys[150], keys[199]].into_iter().collect();
+
+    let mut set = short.clone();
+    set.union_with(&long);
+    assert_set(&set, &BTreeSet::from([1, 5, 150, 199]));
+
+    let mut set = long.clone();
+    set.intersect_with(&short);
+    assert_set(&set, &BTreeSet::from([5]));
+
+    let mut set = long.clone();
+    set.difference_with(&short);
+    assert_set(&set, &BTreeSet::from([150, 199]));
+
+    let mut set = short.clone();
+    set.difference_with(&long);
+    assert_set(&set, &BTreeSet::from([1]));
+}
+
+#[test]
+fn extend_and_collect() {
+    let keys = setup();
+    let mut set: KeySet<Key> = keys.iter().copied().step_by(50).collect();
+    assert_set(&set, &BTreeSet::from([0, 50, 100, 150]));
+
+    set.extend([keys[0], keys[1], keys[199]]);
+    assert_set(&set, &BTreeSet::from([0, 1, 50, 100, 150, 199]));
+
+    let mut iter = set.iter();
+    iter.by_ref().for_each(drop);
+    assert_eq!(iter.next(), None);
+    assert_eq!(KeySet::<Key>::default().iter().next(), None);
+}
@@ -0,0 +1,121 @@
+use std::{cmp::Reverse, collections::HashMap, mem};
+
+use surotto::{keyed_priority_queue::KeyedPriorityQueue, simple::SimpleSurotto, simple_key};
+
+simple_key!(
+    struct Key;
+);
+
+fn setup(len: usize) -> Vec<Key> {
+    let mut primary = SimpleSurotto::<Key, ()>::new();
+    (0..len).map(|_| primary.insert(())).collect()
+}
+
+/// Pops every key, checking that the priorities come out in descending order.
+fn drain(queue: &mut KeyedPriorityQueue<Key, u32>) -> Vec<(Key, u32)> {
+    let mut popped: Vec<(Key, u32)> = Vec::new();
+    while let Some((key, priority)) = queue.pop() {
+        if let Some(&(_, last)) = popped.last() {
+            assert!(last >= priority);
+        }
+        assert!(!queue.contains_key(key));
+        popped.push((key, priority));
+    }
+    popped
+}
+
+#[test]
+fn pop_order() {
+    let keys = setup(5);
+    let mut queue = KeyedPriorityQueue::new();
+    for (&key, priority) in keys.iter().zip([3, 9, 1, 7, 5]) {
+        assert_eq!(queue.push(key, priority), None);
+    }
+    assert_eq!(queue.len(), 5);
+    assert_eq!(queue.peek(), Some((keys[1], &9)));
+
+    let order: Vec<Key> = drain(&mut queue).into_iter().map(|(key, _)| key).collect();
+    assert_eq!(order, [keys[1], keys[3], keys[4], keys[0], keys[2]]);
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn reverse_pops_minimum() {
+    let keys = setup(3);
+    let mut queue = KeyedPriorityQueue::new();
+    queue.push(keys[0], Reverse(2));
+    queue.push(keys[1], Reverse(1));
+    queue.push(keys[2], Reverse(3));
+    assert_eq!(queue.pop(), Some((keys[1], Reverse(1))));
+}
+
+#[test]
+fn change_priority() {
+    let keys = setup(4);
+    let mut queue = KeyedPriorityQueue::new();
+    for (&key, priority) in keys.iter().zip([10, 20, 30, 40]) {
+        queue.push(key, priority);
+    }
+
+    assert_eq!(queue.change_priority(keys[0], 50), Some(10));
+    assert_eq!(queue.peek(), Some((keys[0], &50)));
+    assert_eq!(queue.change_priority(keys[3], 5), Some(40));
+    assert_eq!(queue.push(keys[2], 1), Some(30));
+    assert_eq!(queue.priority(keys[2]), Some(&1));
+    assert_eq!(queue.len(), 4);
+
+    let order: Vec<Key> = drain(&mut queue).into_iter().map(|(key, _)| key).collect();
+    assert_eq!(order, [keys[0], keys[1], keys[3], keys[2]]);
+    assert_eq!(queue.change_priority(keys[0], 1), None);
+}
+
+#[test]
+fn remove_middle() {
+    let keys = setup(7);
+    let mut queue = KeyedPriorityQueue::new();
+    for (i, &key) in keys.iter().enumerate() {
+        queue.push(key, i as u32 * 10);
+    }
+
+    assert_eq!(queue.remove(keys[3]), Some(30));
+    assert_eq!(queue.remove(keys[3]), None);
+    assert_eq!(queue.remove(keys[0]), Some(0));
+    assert_eq!(queue.len(), 5);
+
+    let priorities: Vec<u32> = drain(&mut queue).into_iter().map(|(_, p)| p).collect();
+    assert_eq!(priorities, [60, 50, 40, 20, 10]);
+}
+
+#[test]
+fn against_a_model() {
+    let keys = setup(64);
+    let mut queue = KeyedPriorityQueue::new();
+    let mut model = HashMap::new();
+
+    let mut state = 0x2545_f491_u32;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state
+    };
+    for _ in 0..2000 {
+        let idx = next() as usize % keys.len();
+        let key = keys[idx];
+        let priority = next() % 100;
+        match next() % 4 {
+            0 => assert_eq!(queue.remove(key), model.remove(&idx)),
+            1 => {
+                let old = model.get_mut(&idx).map(|old| mem::replace(old, priority));
+                assert_eq!(queue.change_priority(key, priority), old);
+            }
+            _ => assert_eq!(queue.push(key, priority), model.insert(idx, priority)),
+        }
+        assert_eq!(queue.len(), model.len());
+    }
+
+    let mut expected: Vec<u32> = model.values().copied().collect();
+    expected.sort_unstable_by(|a, b| b.cmp(a));
+    let priorities: Vec<u32> = drain(&mut queue).into_iter().map(|(_, p)| p).collect();
+    assert_eq!(priorities, expected);
+}
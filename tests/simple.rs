@@ -0,0 +1,21 @@
+use surotto::{simple::SimpleSurotto, simple_key};
+
+simple_key!(
+    struct Key;
+);
+
+#[test]
+fn get_disjoint_mut_slice() {
+    let mut surotto = SimpleSurotto::<Key, u32>::new();
+    let a = surotto.insert(1);
+    let b = surotto.insert(2);
+    let c = surotto.insert(3);
+
+    for val in surotto.get_disjoint_mut_slice(&[c, a]).unwrap() {
+        *val *= 10;
+    }
+    assert_eq!(surotto.values().copied().collect::<Vec<_>>(), [10, 2, 30]);
+
+    assert!(surotto.get_disjoint_mut_slice(&[a, b, a]).is_none());
+    assert_eq!(surotto.get_disjoint_mut_slice(&[]).unwrap().len(), 0);
+}
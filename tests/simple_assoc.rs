@@ -0,0 +1,222 @@
+use surotto::{
+    simple::SimpleSurotto,
+    simple_assoc::{entry::Entry, slot_entry::SlotEntry, SimpleAssocSurotto},
+    simple_key,
+};
+
+simple_key!(
+    struct Key;
+);
+
+/// Checks the occupied count against everything which relies on it.
+fn assert_len(surotto: &SimpleAssocSurotto<Key, u32>, len: usize) {
+    assert_eq!(surotto.len(), len);
+    assert_eq!(surotto.is_empty(), len == 0);
+    assert_eq!(surotto.iter().len(), len);
+    assert_eq!(surotto.iter().count(), len);
+    assert_eq!(surotto.iter().rev().count(), len);
+    assert_eq!(surotto.keys().len(), len);
+    assert_eq!(surotto.values().len(), len);
+    assert_eq!(surotto.clone().into_iter().len(), len);
+    assert_eq!(surotto.stats().occupied, len);
+}
+
+fn setup(len: usize) -> (SimpleSurotto<Key, ()>, Vec<Key>) {
+    let mut primary = SimpleSurotto::new();
+    let keys = (0..len).map(|_| primary.insert(())).collect();
+    (primary, keys)
+}
+
+#[test]
+fn insert_and_remove() {
+    let (_, keys) = setup(8);
+    let mut surotto = SimpleAssocSurotto::new();
+    assert_len(&surotto, 0);
+
+    surotto.insert(keys[5], 5);
+    surotto.insert(keys[2], 2);
+    assert_len(&surotto, 2);
+
+    assert_eq!(surotto.insert(keys[5], 50), Some(5));
+    assert_len(&surotto, 2);
+
+    assert_eq!(surotto.remove(keys[5]), Some(50));
+    assert_eq!(surotto.remove(keys[5]), None);
+    assert_eq!(surotto.remove(keys[7]), None);
+    assert_len(&surotto, 1);
+
+    surotto.swap(keys[2], keys[6]);
+    assert_len(&surotto, 1);
+
+    surotto.clear();
+    assert_len(&surotto, 0);
+}
+
+#[test]
+fn shrinking_keeps_len() {
+    let (_, keys) = setup(8);
+    let mut surotto = SimpleAssocSurotto::new();
+    surotto.insert(keys[1], 1);
+    surotto.insert(keys[7], 7);
+    surotto.remove(keys[7]);
+
+    surotto.shrink_to_fit();
+    assert_len(&surotto, 1);
+    surotto.shrink_to(0);
+    assert_len(&surotto, 1);
+    assert_eq!(surotto.stats().highest_occupied, Some(1));
+}
+
+#[test]
+fn entries() {
+    let (_, keys) = setup(4);
+    let mut surotto = SimpleAssocSurotto::new();
+
+    *surotto.entry(keys[0]).or_insert(0) += 1;
+    surotto.entry(keys[1]).or_default();
+    assert_len(&surotto, 2);
+
+    match surotto.entry(keys[3]) {
+        Entry::Vacant(entry) => {
+            entry.insert_entry(3).insert(30);
+        }
+        Entry::Occupied(_) => unreachable!(),
+    }
+    assert_len(&surotto, 3);
+
+    match surotto.entry(keys[0]) {
+        Entry::Occupied(entry) => assert_eq!(entry.remove(), 1),
+        Entry::Vacant(_) => unreachable!(),
+    }
+    assert_len(&surotto, 2);
+    assert_eq!(surotto.get(keys[3]), Some(&30));
+}
+
+#[test]
+fn slot_entries() {
+    let (primary, keys) = setup(6);
+    let mut surotto = SimpleAssocSurotto::new();
+    surotto.insert(keys[0], 0);
+    surotto.insert(keys[1], 1);
+
+    // Keep every slot entry alive at once, so they share the count.
+    let entries: Vec<_> = surotto.iter_entries_for(&primary).collect();
+    assert_eq!(entries.len(), 6);
+    for entry in entries {
+        let idx = keys.iter().position(|&key| key == entry.key()).unwrap() as u32;
+        match entry {
+            SlotEntry::Occupied(entry) if idx == 0 => {
+                entry.remove();
+            }
+            SlotEntry::Occupied(_) => {}
+            SlotEntry::Vacant(entry) if idx == 2 => {
+                entry.insert(idx);
+            }
+            SlotEntry::Vacant(entry) => {
+                entry.insert_entry(idx);
+            }
+        }
+    }
+    assert_len(&surotto, 5);
+}
+
+#[test]
+fn retain_extract_and_split() {
+    let (_, keys) = setup(10);
+    let mut surotto: SimpleAssocSurotto<Key, u32> = SimpleAssocSurotto::new();
+    for (i, &key) in keys.iter().enumerate() {
+        surotto.insert(key, i as u32);
+    }
+
+    surotto.retain(|_, val| *val != 9);
+    assert_len(&surotto, 9);
+
+    // Only the visited elements are extracted.
+    {
+        let mut extracted = surotto.extract_if(|_, val| *val < 6);
+        assert_eq!(extracted.next().map(|(_, val)| val), Some(0));
+        assert_eq!(extracted.next().map(|(_, val)| val), Some(1));
+    }
+    assert_len(&surotto, 7);
+
+    let other = surotto.split_off_where(|_, val| *val > 5);
+    assert_len(&surotto, 4);
+    assert_len(&other, 3);
+}
+
+#[test]
+fn iterators_count_down() {
+    let (_, keys) = setup(6);
+    let mut surotto = SimpleAssocSurotto::new();
+    for &i in &[1, 2, 4] {
+        surotto.insert(keys[i], i as u32);
+    }
+
+    let mut iter = surotto.iter();
+    iter.next();
+    assert_eq!(iter.len(), 2);
+    iter.next_back();
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.count(), 1);
+
+    assert_eq!(surotto.iter_mut().len(), 3);
+    assert_eq!(surotto.values_mut().len(), 3);
+    assert_eq!(surotto.iter_index_range(2..5).len(), 2);
+    assert_eq!(surotto.keys_range(keys[3]..).len(), 1);
+    assert_eq!(surotto.clone().into_keys().len(), 3);
+    assert_eq!(surotto.into_values().len(), 3);
+}
+
+#[test]
+fn get_disjoint_mut_slice() {
+    let (_, keys) = setup(4);
+    let mut surotto = SimpleAssocSurotto::new();
+    surotto.insert(keys[0], 0);
+    surotto.insert(keys[2], 2);
+
+    for val in surotto.get_disjoint_mut_slice(&[keys[2], keys[0]]).unwrap() {
+        *val += 10;
+    }
+    assert_eq!(surotto.get(keys[2]), Some(&12));
+    assert!(surotto
+        .get_disjoint_mut_slice(&[keys[0], keys[0]])
+        .is_none());
+    assert!(surotto.get_disjoint_mut_slice(&[keys[1]]).is_none());
+    assert_len(&surotto, 2);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_retain_and_collect() {
+    use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+
+    let (_, keys) = setup(100);
+    let mut surotto: SimpleAssocSurotto<Key, u32> = keys
+        .clone()
+        .into_par_iter()
+        .enumerate()
+        .map(|(i, key)| (key, i as u32))
+        .collect();
+    assert_len(&surotto, 100);
+
+    surotto.par_retain(|_, val| *val % 3 == 0);
+    assert_len(&surotto, 34);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn deserialize_for() {
+    let (primary, keys) = setup(4);
+    let mut surotto = SimpleAssocSurotto::new();
+    surotto.insert(keys[1], 1);
+    surotto.insert(keys[3], 3);
+
+    let json = serde_json::to_string(&surotto).unwrap();
+    let mut de = serde_json::Deserializer::from_str(&json);
+    let surotto = SimpleAssocSurotto::<Key, u32>::deserialize_for(&primary, &mut de).unwrap();
+    assert_len(&surotto, 2);
+
+    let (small, _) = setup(2);
+    let mut de = serde_json::Deserializer::from_str(&json);
+    assert!(SimpleAssocSurotto::<Key, u32>::deserialize_for(&small, &mut de).is_err());
+}
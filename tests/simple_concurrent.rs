@@ -0,0 +1,139 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+};
+
+use surotto::{simple::SimpleKey, simple_concurrent::SimpleConcurrentSurotto, simple_key};
+
+simple_key!(
+    struct Key;
+);
+
+/// Enough insertions to fill the first two buckets (32 and 64 slots) and start the third.
+const THREADS: usize = 4;
+const PER_THREAD: usize = 50;
+
+#[test]
+fn insert_and_get_across_buckets() {
+    let surotto = SimpleConcurrentSurotto::<Key, usize>::new();
+
+    let keys: Vec<Vec<(Key, usize)>> = thread::scope(|s| {
+        let handles: Vec<_> = (0..THREADS)
+            .map(|t| {
+                let surotto = &surotto;
+                s.spawn(move || {
+                    (0..PER_THREAD)
+                        .map(|i| {
+                            let value = t * PER_THREAD + i;
+                            (surotto.insert(value), value)
+                        })
+                        .collect()
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+
+    assert_eq!(surotto.len(), THREADS * PER_THREAD);
+    for (key, value) in keys.into_iter().flatten() {
+        assert_eq!(*surotto.get(key), value);
+    }
+
+    let mut indices: Vec<usize> = surotto.keys().map(Key::idx).collect();
+    indices.sort_unstable();
+    assert_eq!(indices, (0..THREADS * PER_THREAD).collect::<Vec<_>>());
+}
+
+struct DropCounter(Arc<AtomicUsize>);
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn values_drop_exactly_once() {
+    let drops = Arc::new(AtomicUsize::new(0));
+    let surotto = SimpleConcurrentSurotto::<Key, DropCounter>::new();
+
+    thread::scope(|s| {
+        for _ in 0..THREADS {
+            let surotto = &surotto;
+            let drops = &drops;
+            s.spawn(move || {
+                for i in 0..PER_THREAD {
+                    let reserved = surotto.reserve_key();
+                    // Leave every fifth slot unfilled.
+                    if i % 5 == 0 {
+                        drop(reserved);
+                    } else {
+                        reserved.fill(DropCounter(drops.clone()));
+                    }
+                }
+            });
+        }
+    });
+
+    let filled = THREADS * PER_THREAD / 5 * 4;
+    assert_eq!(surotto.len(), filled);
+    assert_eq!(drops.load(Ordering::Relaxed), 0);
+
+    drop(surotto);
+    assert_eq!(drops.load(Ordering::Relaxed), filled);
+}
+
+#[test]
+fn iterate_while_inserting() {
+    let surotto = SimpleConcurrentSurotto::<Key, usize>::new();
+
+    thread::scope(|s| {
+        for _ in 0..THREADS {
+            let surotto = &surotto;
+            s.spawn(move || {
+                for _ in 0..PER_THREAD {
+                    let reserved = surotto.reserve_key();
+                    let idx = reserved.idx();
+                    reserved.fill(idx);
+                }
+            });
+        }
+
+        let surotto = &surotto;
+        s.spawn(move || {
+            for _ in 0..PER_THREAD {
+                let mut last = None;
+                for (key, &value) in surotto.iter() {
+                    assert_eq!(key.idx(), value);
+                    assert!(last < Some(key.idx()));
+                    last = Some(key.idx());
+                }
+            }
+        });
+    });
+
+    assert!(surotto.iter().all(|(key, &value)| key.idx() == value));
+    assert_eq!(surotto.iter().count(), THREADS * PER_THREAD);
+}
+
+#[test]
+fn unfilled_reserved_key_leaves_a_hole() {
+    let surotto = SimpleConcurrentSurotto::<Key, &str>::new();
+
+    let a = surotto.insert("a");
+    drop(surotto.reserve_key());
+    let c = surotto.insert("c");
+
+    assert_eq!(a.idx(), 0);
+    assert_eq!(c.idx(), 2);
+    assert_eq!(surotto.len(), 2);
+
+    // The iterators run up to every handed out index, the hole included.
+    let iter = surotto.iter();
+    assert_eq!(iter.size_hint(), (0, Some(3)));
+    assert_eq!(iter.collect::<Vec<_>>(), vec![(a, &"a"), (c, &"c")]);
+    assert_eq!(surotto.keys().count(), surotto.len());
+}
@@ -0,0 +1,62 @@
+use surotto::{simple::SimpleSurotto, simple_key, union_find::UnionFind};
+
+simple_key!(
+    struct Key;
+);
+
+fn setup(len: usize) -> Vec<Key> {
+    let mut primary = SimpleSurotto::<Key, ()>::new();
+    (0..len).map(|_| primary.insert(())).collect()
+}
+
+#[test]
+fn singletons() {
+    let keys = setup(3);
+    let mut sets = UnionFind::new();
+    assert_eq!(sets.find(keys[1]), keys[1]);
+    assert!(sets.connected(keys[2], keys[2]));
+    assert!(!sets.connected(keys[0], keys[1]));
+    assert!(sets.classes().is_empty());
+}
+
+#[test]
+fn union_and_find() {
+    let keys = setup(8);
+    let mut sets = UnionFind::new();
+
+    assert!(sets.union(keys[0], keys[1]));
+    assert!(sets.union(keys[2], keys[3]));
+    assert!(sets.union(keys[1], keys[3]));
+    assert!(!sets.union(keys[0], keys[2]));
+    assert!(sets.union(keys[5], keys[6]));
+
+    assert!(sets.connected(keys[0], keys[3]));
+    assert!(sets.connected(keys[6], keys[5]));
+    assert!(!sets.connected(keys[0], keys[5]));
+    assert!(!sets.connected(keys[4], keys[7]));
+
+    let root = sets.find(keys[0]);
+    for &key in &keys[..4] {
+        assert_eq!(sets.find(key), root);
+    }
+
+    assert_eq!(
+        sets.classes(),
+        [
+            vec![keys[0], keys[1], keys[2], keys[3]],
+            vec![keys[5], keys[6]]
+        ]
+    );
+}
+
+#[test]
+fn long_chain() {
+    let keys = setup(100);
+    let mut sets = UnionFind::new();
+    for pair in keys.windows(2) {
+        assert!(sets.union(pair[1], pair[0]));
+    }
+    let root = sets.find(keys[99]);
+    assert!(keys.iter().all(|&key| sets.find(key) == root));
+    assert_eq!(sets.classes(), [keys]);
+}